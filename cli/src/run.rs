@@ -1,10 +1,7 @@
 use std::{io, path::PathBuf};
 
-use athame::{
-    error::{Error, ErrorFormatter},
-    sources::Sources,
-    span::Span,
-};
+use athame::{data::Data, error::ErrorFormatter, ident::Ident, sources::Sources, span::Span};
+use athame_parse::parse_program;
 use clap::Parser;
 
 #[derive(Parser)]
@@ -19,8 +16,16 @@ impl Run {
         let mut sources = Sources::new();
         let id = sources.open(&self.path);
 
+        let (program, mut errors) = parse_program(&sources, id);
+
+        let name = self.path.file_stem().unwrap_or_default().to_string_lossy();
+        let name = Ident::new(name.as_ref(), Span::null());
+
+        let mut data = Data::default();
+        data.add_program(&program, name, &mut errors);
+
         let mut stdout = io::stdout();
         let mut formatter = ErrorFormatter::new(&sources, &mut stdout);
-        formatter.format_error(&error).unwrap();
+        formatter.format_errors(&errors).unwrap();
     }
 }
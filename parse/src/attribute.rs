@@ -0,0 +1,140 @@
+use athame::{
+    attribute::{Attribute, AttributeArg, AttributeStyle, AttributeValue},
+    doc::Doc,
+    error::Error,
+};
+
+use crate::{
+    error::Expected,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::TokenKind,
+};
+
+impl Parse for AttributeValue {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        if let TokenKind::Ident(_) = parser.peek()?.kind {
+            return Ok(Self::Ident(parser.parse()?));
+        }
+
+        match parser.parse() {
+            Ok(literal) => Ok(Self::Literal(literal)),
+            Err(_) => Err(parser.unexpected(&[Expected::Ident, Expected::Literal])),
+        }
+    }
+}
+
+impl Parse for AttributeArg {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+
+        match parser.parse()? {
+            AttributeValue::Ident(key) if parser.next_is(Symbol::Eq) => {
+                let value = parser.parse()?;
+                let span = start.with(parser.previous_span());
+
+                Ok(Self::KeyValue { key, value, span })
+            }
+            value => Ok(Self::Value(value)),
+        }
+    }
+}
+
+impl Parse for Attribute {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Symbol::Hash)?;
+
+        let style = match parser.next_is(Symbol::Bang) {
+            true => AttributeStyle::Inner,
+            false => AttributeStyle::Outer,
+        };
+
+        parser.expect(Symbol::LeftBracket)?;
+        let name = parser.parse()?;
+
+        let args = match parser.next_is(Symbol::LeftParen) {
+            true => parser.parse_list(Symbol::RightParen)?,
+            false => Vec::new(),
+        };
+
+        parser.expect(Symbol::RightBracket)?;
+        let span = start.with(parser.previous_span());
+
+        Ok(Self {
+            style,
+            name,
+            args,
+            span,
+        })
+    }
+}
+
+/// Whether the next tokens start an attribute of `style`.
+fn is_attribute(parser: &mut Parser<'_>, style: AttributeStyle) -> bool {
+    let is_hash = matches!(parser.try_peek(), Some(token) if token.kind == Symbol::Hash);
+    let is_bang = matches!(parser.try_peek_nth(1), Some(token) if token.kind == Symbol::Bang);
+
+    is_hash && is_bang == (style == AttributeStyle::Inner)
+}
+
+fn attributes_of(parser: &mut Parser<'_>, style: AttributeStyle) -> Result<Vec<Attribute>, Error> {
+    let mut attributes = Vec::new();
+
+    while is_attribute(parser, style) {
+        attributes.push(parser.parse()?);
+    }
+
+    Ok(attributes)
+}
+
+/// Parses the doc comments and `#[...]` attributes of the following item, which may
+/// be mixed.
+pub fn docs_and_attributes(parser: &mut Parser<'_>) -> Result<(Vec<Doc>, Vec<Attribute>), Error> {
+    let mut docs = Vec::new();
+    let mut attributes = Vec::new();
+
+    loop {
+        let found_docs = parser.parse_docs();
+        let found_attributes = attributes_of(parser, AttributeStyle::Outer)?;
+
+        if found_docs.is_empty() && found_attributes.is_empty() {
+            return Ok((docs, attributes));
+        }
+
+        docs.extend(found_docs);
+        attributes.extend(found_attributes);
+    }
+}
+
+/// Parses the `//!` doc comments and `#![...]` attributes of the enclosing module,
+/// which may be mixed.
+pub fn inner_docs_and_attributes(
+    parser: &mut Parser<'_>,
+) -> Result<(Vec<Doc>, Vec<Attribute>), Error> {
+    let mut docs = Vec::new();
+    let mut attributes = Vec::new();
+
+    loop {
+        let found_docs = parser.parse_inner_docs();
+        let found_attributes = attributes_of(parser, AttributeStyle::Inner)?;
+
+        if found_docs.is_empty() && found_attributes.is_empty() {
+            return Ok((docs, attributes));
+        }
+
+        docs.extend(found_docs);
+        attributes.extend(found_attributes);
+    }
+}
+
+/// Fails if there are any attributes, for items that don't support them yet.
+pub fn no_attributes(attributes: &[Attribute], item: &str) -> Result<(), Error> {
+    match attributes.first() {
+        Some(attribute) => Err(
+            Error::new(format!("attributes are not supported on {}", item))
+                .with_span(attribute.span),
+        ),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,122 @@
+use athame::{
+    block::Block,
+    error::Error,
+    expr::Expr,
+    stmt::{Stmt, StmtKind},
+};
+
+use crate::{
+    decl::{decl_or_error, is_decl_start},
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::TokenKind,
+};
+
+/// Whether `kind` starts or ends a statement, where the parser resynchronizes after
+/// an error.
+fn is_stmt_boundary(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Symbol(Symbol::Semi | Symbol::Hash)
+            | TokenKind::Keyword(Keyword::Let | Keyword::Return)
+    ) || is_decl_start(kind)
+}
+
+/// Parses a statement, on error the error is reported and the parser skips past the
+/// statement, leaving an [`Expr::Error`] in its place.
+fn stmt_or_error(parser: &mut Parser<'_>) -> Stmt {
+    let start = parser.span();
+    let position = parser.position();
+
+    match parser.parse() {
+        Ok(stmt) => stmt,
+        Err(error) => {
+            parser.report(error);
+            parser.recover(position, is_stmt_boundary);
+            parser.next_is(Symbol::Semi);
+
+            let span = start.with(parser.previous_span());
+
+            Stmt {
+                kind: StmtKind::Expr(Expr::Error(span)),
+                attributes: Vec::new(),
+                span,
+            }
+        }
+    }
+}
+
+/// Takes the last statement if it's an expression without a `;`, which is the value
+/// of the block.
+fn tail_expr(stmts: &mut Vec<Stmt>) -> Option<Box<Expr>> {
+    let is_tail = match stmts.last() {
+        Some(Stmt {
+            kind: StmtKind::Expr(expr),
+            attributes,
+            span,
+        }) => attributes.is_empty() && span.end() == expr.span().end(),
+        _ => false,
+    };
+
+    if !is_tail {
+        return None;
+    }
+
+    match stmts.pop()?.kind {
+        StmtKind::Expr(expr) => Some(Box::new(expr)),
+        _ => None,
+    }
+}
+
+impl Parse for Block {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Symbol::LeftBrace)?;
+
+        let mut stmts = Vec::new();
+
+        loop {
+            if parser.next_is(Symbol::RightBrace) {
+                break;
+            }
+
+            // empty statements
+            if parser.next_is(Symbol::Semi) {
+                continue;
+            }
+
+            let Some(token) = parser.try_peek() else {
+                let error = Error::new("unclosed block")
+                    .with_span(start)
+                    .with_note("expected '}' before the end of file");
+
+                parser.report(error);
+                break;
+            };
+
+            // declarations can't appear in blocks, skip them and carry on with the block
+            if is_decl_start(&token.kind) {
+                let decl_start = parser.span();
+                decl_or_error(parser);
+
+                let error = Error::new("declarations are not allowed in blocks")
+                    .with_span(decl_start.with(parser.previous_span()))
+                    .with_note("move the declaration out of the block");
+
+                parser.report(error);
+                continue;
+            }
+
+            stmts.push(stmt_or_error(parser));
+        }
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self {
+            expr: tail_expr(&mut stmts),
+            stmts,
+            span,
+        })
+    }
+}
@@ -0,0 +1,181 @@
+use athame::{
+    attribute::Attribute,
+    class::Class,
+    doc::Doc,
+    error::Error,
+    field::Field,
+    method::{Method, SelfArgument},
+    ty::Type,
+    visibility::Visibility,
+};
+
+use crate::{
+    attribute::docs_and_attributes,
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::{Token, TokenKind},
+};
+
+/// Parses a field after its doc comments, attributes and visibility, `name: T` or
+/// `name: T = default`.
+pub fn field(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<Field, Error> {
+    let start = parser.span();
+
+    let name = parser.parse()?;
+    parser.expect(Symbol::Colon)?;
+    let ty = parser.parse()?;
+
+    let default = match parser.next_is(Symbol::Eq) {
+        true => Some(parser.parse()?),
+        false => None,
+    };
+
+    let span = start.with(parser.previous_span());
+
+    Ok(Field {
+        visibility,
+        name,
+        ty,
+        default,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+fn is_token<T>(token: Option<&Token>, value: T) -> bool
+where
+    TokenKind: PartialEq<T>,
+{
+    matches!(token, Some(token) if token.kind == value)
+}
+
+/// Parses `self` or `*self`, followed by a `,` unless it's the only argument.
+fn self_argument(parser: &mut Parser<'_>) -> Result<Option<SelfArgument>, Error> {
+    let start = parser.span();
+
+    let is_pointer = is_token(parser.try_peek(), Symbol::Star)
+        && is_token(parser.try_peek_nth(1), Keyword::Self_);
+
+    if is_pointer {
+        parser.next()?;
+    } else if !is_token(parser.try_peek(), Keyword::Self_) {
+        return Ok(None);
+    }
+
+    parser.expect(Keyword::Self_)?;
+    let span = start.with(parser.previous_span());
+
+    if !is_token(parser.try_peek(), Symbol::RightParen) {
+        parser.expect(Symbol::Comma)?;
+    }
+
+    Ok(Some(SelfArgument { is_pointer, span }))
+}
+
+/// Parses a method after its doc comments, attributes and visibility, methods without
+/// a `self` argument are static.
+fn method(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<Method, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Fn)?;
+
+    let name = parser.parse()?;
+    let generics = parser.parse()?;
+
+    parser.expect(Symbol::LeftParen)?;
+    let self_argument = self_argument(parser)?;
+    let arguments = parser.parse_list(Symbol::RightParen)?;
+
+    let return_ty = match parser.next_is(Symbol::Arrow) {
+        true => parser.parse()?,
+        false => Type::Void,
+    };
+
+    let body = parser.parse()?;
+    let span = start.with(parser.previous_span());
+
+    Ok(Method {
+        visibility,
+        name,
+        generics,
+        self_argument,
+        arguments,
+        return_ty,
+        body,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+/// Parses a class declaration after its doc comments, attributes and visibility.
+///
+/// Members are separated by `,`, which is optional after methods.
+pub fn class(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<Class, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Class)?;
+
+    let name = parser.parse()?;
+    let generics = parser.parse()?;
+
+    parser.expect(Symbol::LeftBrace)?;
+
+    let mut fields = Vec::new();
+    let mut methods = Vec::new();
+
+    while !parser.next_is(Symbol::RightBrace) {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let visibility = parser.parse()?;
+
+        if parser.peek()?.kind == Keyword::Fn {
+            methods.push(method(parser, docs, attributes, visibility)?);
+            parser.next_is(Symbol::Comma);
+
+            continue;
+        }
+
+        fields.push(field(parser, docs, attributes, visibility)?);
+
+        if !parser.next_is(Symbol::Comma) {
+            parser.expect(Symbol::RightBrace)?;
+            break;
+        }
+    }
+
+    let span = start.with(parser.previous_span());
+
+    Ok(Class {
+        visibility,
+        name,
+        generics,
+        fields,
+        methods,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+impl Parse for Class {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let visibility = parser.parse()?;
+        class(parser, docs, attributes, visibility)
+    }
+}
@@ -0,0 +1,220 @@
+use athame::{
+    attribute::Attribute,
+    decl::{ConstDecl, Decl, FunctionDecl, StaticDecl},
+    doc::Doc,
+    error::Error,
+    expr::Expr,
+    function::Argument,
+    ident::Ident,
+    ty::Type,
+    visibility::Visibility,
+};
+
+use crate::{
+    attribute::{docs_and_attributes, no_attributes},
+    class::class,
+    enumeration::enumeration,
+    error::Expected,
+    keyword::Keyword,
+    module::module,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::TokenKind,
+    visibility::no_visibility,
+};
+
+impl Parse for Argument {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let name = parser.parse()?;
+        parser.expect(Symbol::Colon)?;
+        let ty = parser.parse()?;
+
+        Ok(Self { name, ty })
+    }
+}
+
+/// Parses a function declaration after its doc comments, attributes and visibility.
+fn function(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<FunctionDecl, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Fn)?;
+
+    let name = parser.parse()?;
+    let generics = parser.parse()?;
+
+    parser.expect(Symbol::LeftParen)?;
+    let arguments = parser.parse_list(Symbol::RightParen)?;
+
+    let return_type = if parser.next_is(Symbol::Arrow) {
+        parser.parse()?
+    } else {
+        Type::Void
+    };
+
+    let body = parser.parse()?;
+    let span = start.with(parser.previous_span());
+
+    Ok(FunctionDecl {
+        visibility,
+        name,
+        generics,
+        arguments,
+        return_type,
+        body,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+impl Parse for FunctionDecl {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let visibility = parser.parse()?;
+        function(parser, docs, attributes, visibility)
+    }
+}
+
+/// Parses `NAME: T = value;`, the rest of a constant or static variable.
+fn global(parser: &mut Parser<'_>) -> Result<(Ident, Type, Expr), Error> {
+    let name = parser.parse()?;
+    parser.expect(Symbol::Colon)?;
+    let ty = parser.parse()?;
+
+    parser.expect(Symbol::Eq)?;
+    let value = parser.parse()?;
+    parser.expect(Symbol::Semi)?;
+
+    Ok((name, ty, value))
+}
+
+/// Parses a constant after its doc comments, attributes and visibility.
+fn constant(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<ConstDecl, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Const)?;
+
+    let (name, ty, value) = global(parser)?;
+    let span = start.with(parser.previous_span());
+
+    Ok(ConstDecl {
+        visibility,
+        name,
+        ty,
+        value,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+/// Parses a static variable after its doc comments, attributes and visibility.
+fn static_variable(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<StaticDecl, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Static)?;
+
+    let (name, ty, value) = global(parser)?;
+    let span = start.with(parser.previous_span());
+
+    Ok(StaticDecl {
+        visibility,
+        name,
+        ty,
+        value,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+impl Parse for Decl {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let visibility = parser.parse()?;
+        let token = parser.peek()?;
+
+        match token.kind {
+            TokenKind::Keyword(Keyword::Fn) => Ok(Self::Function(function(
+                parser, docs, attributes, visibility,
+            )?)),
+            TokenKind::Keyword(Keyword::Class) => {
+                Ok(Self::Class(class(parser, docs, attributes, visibility)?))
+            }
+            TokenKind::Keyword(Keyword::Enum) => Ok(Self::Enum(enumeration(
+                parser, docs, attributes, visibility,
+            )?)),
+            TokenKind::Keyword(Keyword::Mod) => {
+                Ok(Self::Module(module(parser, docs, attributes, visibility)?))
+            }
+            TokenKind::Keyword(Keyword::Use) => {
+                no_attributes(&attributes, "use declarations")?;
+                no_visibility(visibility, "use declarations")?;
+                Ok(Self::Use(parser.parse()?))
+            }
+            TokenKind::Keyword(Keyword::Const) => {
+                Ok(Self::Const(constant(parser, docs, attributes, visibility)?))
+            }
+            TokenKind::Keyword(Keyword::Static) => Ok(Self::Static(static_variable(
+                parser, docs, attributes, visibility,
+            )?)),
+            _ => Err(parser.unexpected(&[
+                Expected::Keyword(Keyword::Fn),
+                Expected::Keyword(Keyword::Class),
+                Expected::Keyword(Keyword::Enum),
+                Expected::Keyword(Keyword::Mod),
+                Expected::Keyword(Keyword::Use),
+                Expected::Keyword(Keyword::Const),
+                Expected::Keyword(Keyword::Static),
+            ])),
+        }
+    }
+}
+
+/// Whether `kind` starts a declaration, where the parser resynchronizes after an error.
+pub fn is_decl_start(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Keyword(
+            Keyword::Pub
+                | Keyword::Fn
+                | Keyword::Class
+                | Keyword::Enum
+                | Keyword::Mod
+                | Keyword::Use
+                | Keyword::Const
+                | Keyword::Static
+        )
+    )
+}
+
+/// Parses a declaration, on error the error is reported and the parser skips to the
+/// next declaration or attribute, leaving a [`Decl::Error`] in its place.
+pub fn decl_or_error(parser: &mut Parser<'_>) -> Decl {
+    let start = parser.span();
+    let position = parser.position();
+
+    match parser.parse() {
+        Ok(decl) => decl,
+        Err(error) => {
+            parser.report(error);
+            parser.recover(position, |kind| {
+                is_decl_start(kind) || *kind == Symbol::Hash
+            });
+
+            Decl::Error(start.with(parser.previous_span()))
+        }
+    }
+}
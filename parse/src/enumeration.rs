@@ -0,0 +1,100 @@
+use athame::{
+    attribute::Attribute,
+    doc::Doc,
+    enumeration::{Enum, Variant, VariantKind},
+    error::Error,
+    visibility::Visibility,
+};
+
+use crate::{
+    attribute::docs_and_attributes,
+    class::field,
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+};
+
+impl Parse for Variant {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let start = parser.span();
+
+        let name = parser.parse()?;
+
+        let kind = if parser.next_is(Symbol::LeftParen) {
+            VariantKind::Tuple(parser.parse_list(Symbol::RightParen)?)
+        } else if parser.next_is(Symbol::LeftBrace) {
+            let mut fields = Vec::new();
+
+            while !parser.next_is(Symbol::RightBrace) {
+                let (docs, attributes) = docs_and_attributes(parser)?;
+                let mut field = field(parser, docs, attributes, Visibility::Private)?;
+
+                if let Some(default) = field.default.take() {
+                    let error = Error::new("variant fields cannot have default values")
+                        .with_span(default.span());
+
+                    parser.report(error);
+                }
+
+                fields.push(field);
+
+                if !parser.next_is(Symbol::Comma) {
+                    parser.expect(Symbol::RightBrace)?;
+                    break;
+                }
+            }
+
+            VariantKind::Struct(fields)
+        } else {
+            VariantKind::Unit
+        };
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self {
+            name,
+            kind,
+            docs,
+            attributes,
+            span,
+        })
+    }
+}
+
+/// Parses an enum declaration after its doc comments, attributes and visibility.
+pub fn enumeration(
+    parser: &mut Parser<'_>,
+    docs: Vec<Doc>,
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<Enum, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Enum)?;
+
+    let name = parser.parse()?;
+    let generics = parser.parse()?;
+
+    parser.expect(Symbol::LeftBrace)?;
+    let variants = parser.parse_list(Symbol::RightBrace)?;
+
+    let span = start.with(parser.previous_span());
+
+    Ok(Enum {
+        visibility,
+        name,
+        generics,
+        variants,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+impl Parse for Enum {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let visibility = parser.parse()?;
+        enumeration(parser, docs, attributes, visibility)
+    }
+}
@@ -1,31 +1,44 @@
-use athame::error::Error;
+use crate::{
+    keyword::{ContextualKeyword, Keyword},
+    symbol::Symbol,
+};
 
-use crate::{keyword::Keyword, symbol::Symbol, token::Token};
-
-#[allow(dead_code)]
+/// Something the parser would have accepted where it failed.
+#[derive(Clone, Debug, PartialEq)]
 pub enum Expected {
     Ident,
+    Literal,
     Symbol(Symbol),
     Keyword(Keyword),
+    ContextualKeyword(ContextualKeyword),
+}
+
+impl From<Symbol> for Expected {
+    fn from(symbol: Symbol) -> Self {
+        Self::Symbol(symbol)
+    }
+}
+
+impl From<Keyword> for Expected {
+    fn from(keyword: Keyword) -> Self {
+        Self::Keyword(keyword)
+    }
+}
+
+impl From<ContextualKeyword> for Expected {
+    fn from(keyword: ContextualKeyword) -> Self {
+        Self::ContextualKeyword(keyword)
+    }
 }
 
 impl std::fmt::Display for Expected {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Ident => write!(f, "identifier"),
+            Self::Literal => write!(f, "literal"),
             Self::Symbol(symbol) => write!(f, "'{}'", symbol),
             Self::Keyword(keyword) => write!(f, "'{}'", keyword),
+            Self::ContextualKeyword(keyword) => write!(f, "'{}'", keyword),
         }
     }
 }
-
-pub fn expected_any(token: &Token, tokens: &[Expected]) -> Error {
-    let strings: Vec<_> = tokens.iter().map(|e| format!("{}", e)).collect();
-
-    Error::new(format!("unexpected token '{}'", token.kind))
-        .with_span(token.span)
-        .with_note(format!(
-            "expected one of the following: {}",
-            strings.join(", ")
-        ))
-}
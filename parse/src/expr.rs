@@ -1,27 +1,88 @@
 use athame::{
     error::Error,
     expr::{
-        AssignExpr, BinOp, BinaryExpr, CallExpr, Expr, FieldExpr, IndexExpr, ParenExpr, UnaryOp,
+        AssignExpr, BinOp, BinaryExpr, BreakExpr, CallExpr, ContinueExpr, Expr, FieldExpr, ForExpr,
+        IfExpr, IndexExpr, LiteralExpr, LiteralKind, LoopExpr, MatchArm, MatchExpr, MethodCallExpr,
+        ParenExpr, UnaryOp, WhileExpr,
     },
     ident::Ident,
+    int::Int,
+    pattern::Pattern,
+    specialization::Spec,
 };
 
 use crate::{
-    error::{expected_any, Expected},
+    error::Expected,
+    keyword::Keyword,
     parser::{Parse, Parser},
     symbol::Symbol,
-    token::TokenKind,
+    token::{Token, TokenKind},
 };
 
+/// Parses a literal. Literals of signed integer types may only reach the magnitude of
+/// the type's minimum value if `is_negated`, the lexer allows it for `-` to work.
+fn literal(parser: &mut Parser<'_>, is_negated: bool) -> Result<LiteralExpr, Error> {
+    let token = parser.peek()?;
+    let span = token.span;
+
+    let kind = match token.kind {
+        TokenKind::Int(ref int) => LiteralKind::Int {
+            value: int.value,
+            suffix: int.suffix,
+        },
+        TokenKind::Float(ref float) => LiteralKind::Float {
+            value: float.value,
+            suffix: float.suffix,
+        },
+        // byte literals are `u8` integers
+        TokenKind::Byte(byte) => LiteralKind::Int {
+            value: byte as u128,
+            suffix: Some(Int::u8()),
+        },
+        TokenKind::Keyword(Keyword::True) => LiteralKind::Bool(true),
+        TokenKind::Keyword(Keyword::False) => LiteralKind::Bool(false),
+        TokenKind::Char(c) => LiteralKind::Char(c),
+        TokenKind::String(ref string) => LiteralKind::String(string.clone()),
+        TokenKind::ByteString(ref bytes) => LiteralKind::ByteString(bytes.clone()),
+        _ => return Err(parser.unexpected(&[Expected::Literal])),
+    };
+
+    parser.next()?;
+
+    if let LiteralKind::Int {
+        value,
+        suffix: Some(int),
+    } = kind
+    {
+        let max = (1u128 << (int.size.unwrap_or(64) - 1)) - 1;
+
+        if int.signed && !is_negated && value > max {
+            let error = Error::new(format!("literal out of range for '{}'", int))
+                .with_span(span)
+                .with_note(format!("the maximum value of '{}' is {}", int, max));
+
+            parser.report(error);
+        }
+    }
+
+    Ok(LiteralExpr { kind, span })
+}
+
+impl Parse for LiteralExpr {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        literal(parser, false)
+    }
+}
+
 impl Parse for ParenExpr {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
         let start = parser.span();
 
         parser.expect(Symbol::LeftParen)?;
         let expr = parser.parse::<Expr>()?;
         parser.expect(Symbol::RightParen)?;
 
-        let end = parser.span();
+        let end = parser.previous_span();
 
         Ok(Self {
             expr: Box::new(expr),
@@ -30,98 +91,392 @@ impl Parse for ParenExpr {
     }
 }
 
-fn term(parser: &mut Parser) -> Result<Expr, Error> {
+/// Parses an optional loop label, `'name`.
+fn label(parser: &mut Parser<'_>) -> Option<Ident> {
+    let token = parser.try_peek()?;
+
+    let TokenKind::Label(ref name) = token.kind else {
+        return None;
+    };
+
+    let label = Ident::new(name.as_str(), token.span);
+    parser.next().ok()?;
+
+    Some(label)
+}
+
+impl Parse for IfExpr {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::If)?;
+
+        let condition = parser.parse::<Expr>()?;
+        let then_block = parser.parse()?;
+
+        let else_branch = match parser.next_is(Keyword::Else) {
+            true => match parser.peek()?.kind {
+                TokenKind::Keyword(Keyword::If) => Some(Box::new(Expr::If(parser.parse()?))),
+                _ => Some(Box::new(Expr::Block(parser.parse()?))),
+            },
+            false => None,
+        };
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self {
+            condition: Box::new(condition),
+            then_block,
+            else_branch,
+            span,
+        })
+    }
+}
+
+/// Parses a `loop`, `while` or `for` loop, with an optional label.
+fn looping(parser: &mut Parser<'_>) -> Result<Expr, Error> {
+    let start = parser.span();
+
+    let label = label(parser);
+
+    if label.is_some() {
+        parser.expect(Symbol::Colon)?;
+    }
+
     let token = parser.next()?;
 
     match token.kind {
-        TokenKind::Symbol(Symbol::LeftParen) => {
-            let expr = parser.parse::<ParenExpr>()?;
+        TokenKind::Keyword(Keyword::Loop) => {
+            let body = parser.parse()?;
+            let span = start.with(parser.previous_span());
 
-            Ok(Expr::Paren(expr))
+            Ok(Expr::Loop(LoopExpr { label, body, span }))
+        }
+        TokenKind::Keyword(Keyword::While) => {
+            let condition = parser.parse::<Expr>()?;
+            let body = parser.parse()?;
+            let span = start.with(parser.previous_span());
+
+            Ok(Expr::While(WhileExpr {
+                label,
+                condition: Box::new(condition),
+                body,
+                span,
+            }))
         }
-        _ => Err(Error::new("unexpected token")),
+        TokenKind::Keyword(Keyword::For) => {
+            let binding = parser.parse()?;
+            parser.expect(Keyword::In)?;
+            let iterator = parser.parse::<Expr>()?;
+            let body = parser.parse()?;
+            let span = start.with(parser.previous_span());
+
+            Ok(Expr::For(ForExpr {
+                label,
+                binding,
+                iterator: Box::new(iterator),
+                body,
+                span,
+            }))
+        }
+        _ => Err(parser
+            .unexpected(&[
+                Expected::Keyword(Keyword::Loop),
+                Expected::Keyword(Keyword::While),
+                Expected::Keyword(Keyword::For),
+            ])
+            .with_note("only loops can be labeled")),
     }
 }
 
-fn field(parser: &mut Parser) -> Result<Expr, Error> {
-    let expr = term(parser)?;
+/// Whether `token` can't start an expression but can follow one, so `break` and
+/// `return` have no value.
+pub fn is_expr_end(token: Option<&Token>) -> bool {
+    let Some(token) = token else {
+        return true;
+    };
+
+    matches!(
+        token.kind,
+        TokenKind::Symbol(
+            Symbol::Semi
+                | Symbol::Comma
+                | Symbol::RightBrace
+                | Symbol::RightParen
+                | Symbol::RightBracket
+        )
+    )
+}
 
-    if parser.next_is(Symbol::Dot) {
-        let field = parser.parse::<Ident>()?;
-        let span = expr.span().with(field.span());
+impl Parse for BreakExpr {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::Break)?;
 
-        Ok(Expr::Field(FieldExpr {
-            class: Box::new(expr),
-            field,
-            span,
-        }))
-    } else {
-        Ok(expr)
+        let label = label(parser);
+
+        let value = match is_expr_end(parser.try_peek()) {
+            true => None,
+            false => Some(Box::new(parser.parse()?)),
+        };
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self { label, value, span })
     }
 }
 
-fn call(parser: &mut Parser) -> Result<Expr, Error> {
-    let expr = field(parser)?;
+impl Parse for ContinueExpr {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::Continue)?;
 
-    if parser.next_is(Symbol::LeftParen) {
-        let arguments = parser.parse_list::<Expr>(&TokenKind::Symbol(Symbol::Comma))?;
-        let span = expr.span().with(arguments.last().unwrap().span());
+        let label = label(parser);
+        let span = start.with(parser.previous_span());
 
-        Ok(Expr::Call(CallExpr {
-            callee: Box::new(expr),
-            arguments,
+        Ok(Self { label, span })
+    }
+}
+
+impl Parse for MatchArm {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let pattern = parser.parse::<Pattern>()?;
+        parser.expect(Symbol::FatArrow)?;
+
+        let body = parser.parse::<Expr>()?;
+        let span = pattern.span.with(parser.previous_span());
+
+        Ok(Self {
+            pattern,
+            body,
             span,
-        }))
-    } else {
-        Ok(expr)
+        })
     }
 }
 
-fn index(parser: &mut Parser) -> Result<Expr, Error> {
-    let expr = call(parser)?;
+impl Parse for MatchExpr {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::Match)?;
+
+        let expr = parser.parse::<Expr>()?;
+        parser.expect(Symbol::LeftBrace)?;
+
+        let mut arms = Vec::new();
+
+        // arms are separated by `,`, which is optional after a block
+        while !parser.next_is(Symbol::RightBrace) {
+            let arm = parser.parse::<MatchArm>()?;
+            let is_block_like = arm.body.is_block_like();
+            arms.push(arm);
+
+            if !parser.next_is(Symbol::Comma) && !is_block_like {
+                parser.expect(Symbol::RightBrace)?;
+                break;
+            }
+        }
 
-    if parser.next_is(Symbol::LeftBracket) {
-        let index = parser.parse::<Expr>()?;
-        parser.expect(Symbol::RightBracket)?;
-        let span = expr.span().with(index.span());
+        let span = start.with(parser.previous_span());
 
-        Ok(Expr::Index(IndexExpr {
+        Ok(Self {
             expr: Box::new(expr),
-            index: Box::new(index),
+            arms,
             span,
-        }))
-    } else {
-        Ok(expr)
+        })
+    }
+}
+
+/// Whether `token` starts an expression ending with a block.
+pub fn is_block_like(token: &Token) -> bool {
+    matches!(
+        token.kind,
+        TokenKind::Label(_)
+            | TokenKind::Symbol(Symbol::LeftBrace)
+            | TokenKind::Keyword(
+                Keyword::If | Keyword::Loop | Keyword::While | Keyword::For | Keyword::Match
+            )
+    )
+}
+
+/// Parses an expression ending with a block, i.e. a block, an `if`, a `match` or a
+/// loop.
+pub fn block_like(parser: &mut Parser<'_>) -> Result<Expr, Error> {
+    let token = parser.peek()?;
+
+    match token.kind {
+        TokenKind::Symbol(Symbol::LeftBrace) => Ok(Expr::Block(parser.parse()?)),
+        TokenKind::Keyword(Keyword::If) => Ok(Expr::If(parser.parse()?)),
+        TokenKind::Keyword(Keyword::Match) => Ok(Expr::Match(parser.parse()?)),
+        _ => looping(parser),
+    }
+}
+
+fn term(parser: &mut Parser<'_>) -> Result<Expr, Error> {
+    let token = parser.peek()?;
+
+    if is_block_like(token) {
+        return block_like(parser);
+    }
+
+    match token.kind {
+        TokenKind::Int(_)
+        | TokenKind::Float(_)
+        | TokenKind::Byte(_)
+        | TokenKind::Char(_)
+        | TokenKind::String(_)
+        | TokenKind::ByteString(_)
+        | TokenKind::Keyword(Keyword::True | Keyword::False) => Ok(Expr::Literal(parser.parse()?)),
+        TokenKind::Symbol(Symbol::LeftParen) => Ok(Expr::Paren(parser.parse()?)),
+        TokenKind::Ident(_)
+        | TokenKind::Symbol(Symbol::ColonColon)
+        | TokenKind::Keyword(Keyword::Self_ | Keyword::Super) => Ok(Expr::Path(parser.parse()?)),
+        TokenKind::Keyword(Keyword::Break) => Ok(Expr::Break(parser.parse()?)),
+        TokenKind::Keyword(Keyword::Continue) => Ok(Expr::Continue(parser.parse()?)),
+        // the lexer already reported the invalid token
+        TokenKind::Error => {
+            let span = token.span;
+            parser.next()?;
+
+            Ok(Expr::Error(span))
+        }
+        _ => Err(parser.unexpected(&[
+            Expected::Literal,
+            Expected::Ident,
+            Expected::Symbol(Symbol::LeftParen),
+            Expected::Symbol(Symbol::LeftBrace),
+            Expected::Symbol(Symbol::ColonColon),
+            Expected::Keyword(Keyword::Self_),
+            Expected::Keyword(Keyword::Super),
+            Expected::Keyword(Keyword::If),
+            Expected::Keyword(Keyword::Match),
+            Expected::Keyword(Keyword::Loop),
+            Expected::Keyword(Keyword::While),
+            Expected::Keyword(Keyword::For),
+            Expected::Keyword(Keyword::Break),
+            Expected::Keyword(Keyword::Continue),
+        ])),
     }
 }
 
+/// Parses the arguments of a call, after the `(`.
+fn arguments(parser: &mut Parser<'_>) -> Result<Vec<Expr>, Error> {
+    parser.parse_list(Symbol::RightParen)
+}
+
+/// Parses `.field` or `.method::<T>(args)`, after the `.`.
+fn member(parser: &mut Parser<'_>, expr: Expr) -> Result<Expr, Error> {
+    let name = parser.parse::<Ident>()?;
+
+    let spec = match parser.next_is(Symbol::ColonColon) {
+        true => parser.parse::<Spec>()?,
+        false => Spec::empty(),
+    };
+
+    if !spec.is_empty() || parser.next_is(Symbol::LeftParen) {
+        if !spec.is_empty() {
+            parser.expect(Symbol::LeftParen)?;
+        }
+
+        let arguments = arguments(parser)?;
+        let span = expr.span().with(parser.previous_span());
+
+        return Ok(Expr::MethodCall(MethodCallExpr {
+            receiver: Box::new(expr),
+            method: name,
+            spec,
+            arguments,
+            span,
+        }));
+    }
+
+    let span = expr.span().with(name.span());
+
+    Ok(Expr::Field(FieldExpr {
+        class: Box::new(expr),
+        field: name,
+        span,
+    }))
+}
+
+/// Parses any sequence of field accesses, calls, method calls and indexing.
+fn postfix(parser: &mut Parser<'_>) -> Result<Expr, Error> {
+    let mut expr = term(parser)?;
+
+    loop {
+        if parser.next_is(Symbol::Dot) {
+            expr = member(parser, expr)?;
+        } else if parser.next_is(Symbol::LeftParen) {
+            let arguments = arguments(parser)?;
+            let span = expr.span().with(parser.previous_span());
+
+            expr = Expr::Call(CallExpr {
+                callee: Box::new(expr),
+                arguments,
+                span,
+            });
+        } else if parser.next_is(Symbol::LeftBracket) {
+            let index = parser.parse::<Expr>()?;
+            parser.expect(Symbol::RightBracket)?;
+            let span = expr.span().with(parser.previous_span());
+
+            expr = Expr::Index(IndexExpr {
+                expr: Box::new(expr),
+                index: Box::new(index),
+                span,
+            });
+        } else {
+            break;
+        }
+    }
+
+    Ok(expr)
+}
+
 impl Parse for UnaryOp {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
-        let token = parser.next()?;
-
-        match token.kind {
-            TokenKind::Symbol(Symbol::Minus) => Ok(Self::Neg),
-            TokenKind::Symbol(Symbol::Bang) => Ok(Self::Not),
-            TokenKind::Symbol(Symbol::Amp) => Ok(Self::Ref),
-            TokenKind::Symbol(Symbol::Star) => Ok(Self::Deref),
-            _ => Err(expected_any(
-                token,
-                &[
-                    Expected::Symbol(Symbol::Minus),
-                    Expected::Symbol(Symbol::Bang),
-                    Expected::Symbol(Symbol::Amp),
-                    Expected::Symbol(Symbol::Star),
-                ],
-            )),
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        // `&` is matched with `next_is`, so that `&&` is split into two references
+        if parser.next_is(Symbol::Minus) {
+            Ok(Self::Neg)
+        } else if parser.next_is(Symbol::Bang) {
+            Ok(Self::Not)
+        } else if parser.next_is(Symbol::Amp) {
+            Ok(Self::Ref)
+        } else if parser.next_is(Symbol::Star) {
+            Ok(Self::Deref)
+        } else {
+            // the operators were recorded as expected by `next_is`
+            Err(parser.unexpected(&[]))
         }
     }
 }
 
-fn unary(parser: &mut Parser) -> Result<Expr, Error> {
+/// Parses the operand of `-`, an integer literal is negated directly unless it's the
+/// receiver of a postfix expression.
+fn negated(parser: &mut Parser<'_>) -> Result<Expr, Error> {
+    let is_int =
+        matches!(parser.try_peek(), Some(token) if matches!(token.kind, TokenKind::Int(_)));
+    let is_postfix = matches!(
+        parser.try_peek_nth(1),
+        Some(token) if matches!(
+            token.kind,
+            TokenKind::Symbol(Symbol::Dot | Symbol::LeftParen | Symbol::LeftBracket)
+        )
+    );
+
+    if is_int && !is_postfix {
+        return Ok(Expr::Literal(literal(parser, true)?));
+    }
+
+    unary(parser)
+}
+
+fn unary(parser: &mut Parser<'_>) -> Result<Expr, Error> {
     let start = parser.span();
 
     if let Some(op) = parser.try_parse::<UnaryOp>() {
-        let expr = unary(parser)?;
+        let expr = match op {
+            UnaryOp::Neg => negated(parser)?,
+            _ => unary(parser)?,
+        };
         let span = start.with(expr.span());
 
         Ok(Expr::Unary(athame::expr::UnaryExpr {
@@ -130,86 +485,114 @@ fn unary(parser: &mut Parser) -> Result<Expr, Error> {
             span,
         }))
     } else {
-        index(parser)
+        postfix(parser)
     }
 }
 
+/// The binary operator `kind` is, if any.
+fn bin_op(kind: &TokenKind) -> Option<BinOp> {
+    let TokenKind::Symbol(symbol) = kind else {
+        return None;
+    };
+
+    match symbol {
+        Symbol::Plus => Some(BinOp::Add),
+        Symbol::Minus => Some(BinOp::Sub),
+        Symbol::Star => Some(BinOp::Mul),
+        Symbol::Slash => Some(BinOp::Div),
+        Symbol::Percent => Some(BinOp::Mod),
+        Symbol::Amp => Some(BinOp::BitAnd),
+        Symbol::Pipe => Some(BinOp::BitOr),
+        Symbol::Caret => Some(BinOp::BitXor),
+        Symbol::Shl => Some(BinOp::Shl),
+        Symbol::Shr => Some(BinOp::Shr),
+        Symbol::EqEq => Some(BinOp::Eq),
+        Symbol::NotEq => Some(BinOp::Ne),
+        Symbol::Lt => Some(BinOp::Lt),
+        Symbol::LtEq => Some(BinOp::Le),
+        Symbol::Gt => Some(BinOp::Gt),
+        Symbol::GtEq => Some(BinOp::Ge),
+        Symbol::AndAnd => Some(BinOp::And),
+        Symbol::OrOr => Some(BinOp::Or),
+        _ => None,
+    }
+}
+
+/// The symbols of the binary operators, see [`bin_op`].
+const BIN_OPS: [Symbol; 18] = [
+    Symbol::Plus,
+    Symbol::Minus,
+    Symbol::Star,
+    Symbol::Slash,
+    Symbol::Percent,
+    Symbol::Amp,
+    Symbol::Pipe,
+    Symbol::Caret,
+    Symbol::Shl,
+    Symbol::Shr,
+    Symbol::EqEq,
+    Symbol::NotEq,
+    Symbol::Lt,
+    Symbol::LtEq,
+    Symbol::Gt,
+    Symbol::GtEq,
+    Symbol::AndAnd,
+    Symbol::OrOr,
+];
+
 impl Parse for BinOp {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
-        let token = parser.next()?;
-
-        match token.kind {
-            TokenKind::Symbol(Symbol::Plus) => Ok(Self::Add),
-            TokenKind::Symbol(Symbol::Minus) => Ok(Self::Sub),
-            TokenKind::Symbol(Symbol::Star) => Ok(Self::Mul),
-            TokenKind::Symbol(Symbol::Slash) => Ok(Self::Div),
-            TokenKind::Symbol(Symbol::Percent) => Ok(Self::Mod),
-            TokenKind::Symbol(Symbol::Amp) => Ok(Self::And),
-            TokenKind::Symbol(Symbol::EqEq) => Ok(Self::Eq),
-            TokenKind::Symbol(Symbol::NotEq) => Ok(Self::Ne),
-            TokenKind::Symbol(Symbol::Lt) => Ok(Self::Lt),
-            TokenKind::Symbol(Symbol::LtEq) => Ok(Self::Le),
-            TokenKind::Symbol(Symbol::Gt) => Ok(Self::Gt),
-            TokenKind::Symbol(Symbol::GtEq) => Ok(Self::Ge),
-            _ => Err(expected_any(
-                token,
-                &[
-                    Expected::Symbol(Symbol::Plus),
-                    Expected::Symbol(Symbol::Minus),
-                    Expected::Symbol(Symbol::Star),
-                    Expected::Symbol(Symbol::Slash),
-                    Expected::Symbol(Symbol::Percent),
-                    Expected::Symbol(Symbol::Amp),
-                    Expected::Symbol(Symbol::EqEq),
-                    Expected::Symbol(Symbol::NotEq),
-                    Expected::Symbol(Symbol::Lt),
-                    Expected::Symbol(Symbol::LtEq),
-                    Expected::Symbol(Symbol::Gt),
-                    Expected::Symbol(Symbol::GtEq),
-                ],
-            )),
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let token = parser.peek()?;
+
+        match bin_op(&token.kind) {
+            Some(op) => {
+                parser.next()?;
+                Ok(op)
+            }
+            None => Err(parser.unexpected(&BIN_OPS.map(Expected::Symbol))),
         }
     }
 }
 
-fn binary(parser: &mut Parser) -> Result<Expr, Error> {
-    let expr = unary(parser)?;
+/// Parses binary expressions with operators binding tighter than `limit`, all binary
+/// operators are left associative.
+fn binary(parser: &mut Parser<'_>, limit: u8) -> Result<Expr, Error> {
+    let mut lhs = unary(parser)?;
 
-    if let Some(op) = parser.try_parse::<BinOp>() {
-        let rhs = binary(parser)?;
-        let span = expr.span().with(rhs.span());
+    loop {
+        let op = match parser.try_peek() {
+            Some(token) => bin_op(&token.kind),
+            None => None,
+        };
 
-        if let Expr::Binary(ref rhs) = rhs {
-            if rhs.op.precedence() > op.precedence() {
-                let expr = Expr::Binary(BinaryExpr {
-                    lhs: Box::new(expr),
-                    op,
-                    rhs: rhs.lhs.clone(),
-                    span: span.with(rhs.span),
-                });
-
-                return Ok(Expr::Binary(BinaryExpr {
-                    lhs: Box::new(expr),
-                    op: rhs.op.clone(),
-                    rhs: rhs.rhs.clone(),
-                    span: span.with(rhs.span),
-                }));
-            }
+        let Some(op) = op else {
+            parser.record_expected_all(&BIN_OPS.map(Expected::Symbol));
+            break;
+        };
+
+        if op.precedence() >= limit {
+            break;
         }
 
-        Ok(Expr::Binary(BinaryExpr {
-            lhs: Box::new(expr),
+        parser.next()?;
+
+        // operators of the same precedence are left to the enclosing loop
+        let rhs = binary(parser, op.precedence())?;
+        let span = lhs.span().with(rhs.span());
+
+        lhs = Expr::Binary(BinaryExpr {
+            lhs: Box::new(lhs),
             op,
             rhs: Box::new(rhs),
             span,
-        }))
-    } else {
-        Ok(expr)
+        });
     }
+
+    Ok(lhs)
 }
 
-fn assign(parser: &mut Parser) -> Result<Expr, Error> {
-    let expr = binary(parser)?;
+fn assign(parser: &mut Parser<'_>) -> Result<Expr, Error> {
+    let expr = binary(parser, u8::MAX)?;
 
     if parser.next_is(Symbol::Eq) {
         let rhs = assign(parser)?;
@@ -226,7 +609,7 @@ fn assign(parser: &mut Parser) -> Result<Expr, Error> {
 }
 
 impl Parse for Expr {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
         assign(parser)
     }
 }
@@ -6,11 +6,10 @@ use athame::{
 use crate::{
     parser::{Parse, Parser},
     symbol::Symbol,
-    token::TokenKind,
 };
 
 impl Parse for Generic {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
         Ok(Self {
             name: parser.parse()?,
         })
@@ -18,11 +17,11 @@ impl Parse for Generic {
 }
 
 impl Parse for Generics {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
         let mut params = Vec::new();
 
         if parser.next_is(Symbol::Lt) {
-            params = parser.parse_list::<Generic>(&TokenKind::Symbol(Symbol::Gt))?;
+            params = parser.parse_list::<Generic>(Symbol::Gt)?;
         }
 
         Ok(Self { params })
@@ -0,0 +1,421 @@
+use std::ops::Range;
+
+use athame::{
+    error::{Error, Errors},
+    program::Program,
+    sources::{Source, SourceId},
+    span::Span,
+};
+
+use crate::{
+    lexer::{IdentChecker, Lexer},
+    parser::{Checkpoint, Parser},
+    program::{program_decls, program_header},
+    shift::Shift,
+    symbol::Symbol,
+    token::{Token, TokenKind},
+};
+
+/// The number of bytes the lexer may look past the end of a token.
+const LOOKAHEAD: usize = 4;
+
+/// A replacement of `range` in a source with `text`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, text: impl Into<String>) -> Self {
+        Self {
+            range,
+            text: text.into(),
+        }
+    }
+
+    pub fn insert(index: usize, text: impl Into<String>) -> Self {
+        Self::new(index..index, text)
+    }
+
+    pub fn delete(range: Range<usize>) -> Self {
+        Self::new(range, "")
+    }
+
+    /// The change in length of the source.
+    pub fn delta(&self) -> isize {
+        self.text.len() as isize - self.range.len() as isize
+    }
+
+    /// The end of the replaced text in the edited source.
+    pub fn new_end(&self) -> usize {
+        self.range.start + self.text.len()
+    }
+
+    pub fn apply(&self, source: &Source) -> Source {
+        let mut text = String::from(source.source());
+        text.replace_range(self.range.clone(), &self.text);
+
+        Source::new(source.path(), text)
+    }
+}
+
+/// Moves a token, and its trivia, by `delta` bytes.
+pub fn shift_token(token: &Token, delta: isize) -> Token {
+    let mut token = token.clone();
+    token.span = token.span.shifted(delta);
+
+    for trivia in token.leading.iter_mut().chain(token.trailing.iter_mut()) {
+        trivia.span = trivia.span.shifted(delta);
+    }
+
+    token
+}
+
+/// Tokens that may be the start of a raw string, which looks arbitrarily far ahead.
+fn is_raw_string_prefix(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Symbol(Symbol::Hash) => true,
+        TokenKind::Ident(ref ident) => ident == "r" || ident == "br",
+        _ => false,
+    }
+}
+
+/// The start of the line containing `index`.
+fn line_start(source: &Source, index: usize) -> usize {
+    source.source()[..index]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1)
+}
+
+/// Tokens that may be an unterminated character literal, which looks for its closing
+/// `'` up to the end of its line.
+fn is_unterminated_char(token: &Token, source: &Source) -> bool {
+    let text = &source.source()[token.span.index..];
+    token.kind == TokenKind::Error && (text.starts_with('\'') || text.starts_with("b'"))
+}
+
+impl<'a> Lexer<'a> {
+    /// Lexes the source after `edit` was applied to it, given the `tokens` of the source
+    /// before the edit.
+    ///
+    /// Only the tokens around the edit are lexed again, the tokens after them are reused
+    /// and moved. The result is the same as lexing the entire source, as long as `tokens`
+    /// were lexed in the same mode as `self`. Only errors in the lexed region are reported.
+    pub fn relex(&mut self, tokens: &[Token], edit: &TextEdit) -> Vec<Token> {
+        let lossless = self.is_lossless();
+        let full_end = |token: &Token| match lossless {
+            true => token.full_span().end(),
+            false => token.span.end(),
+        };
+
+        // the first token that could be changed by the edit, tokens before it might
+        // still have looked ahead into the edit
+        let mut first = tokens.partition_point(|token| full_end(token) < edit.range.start);
+
+        while first > 0 {
+            let previous = &tokens[first - 1];
+
+            if full_end(previous) + LOOKAHEAD <= edit.range.start && !is_raw_string_prefix(previous)
+            {
+                break;
+            }
+
+            first -= 1;
+        }
+
+        // the text before the edit is unchanged, so the old tokens on the line of the
+        // edit can be checked in the edited source
+        let line_start = line_start(self.source(), edit.range.start);
+        let line = tokens[..first].partition_point(|token| token.span.index < line_start);
+
+        let unterminated = tokens[line..first]
+            .iter()
+            .position(|token| is_unterminated_char(token, self.source()));
+
+        if let Some(index) = unterminated {
+            first = line + index;
+        }
+
+        let start = match first {
+            0 => 0,
+            _ => full_end(&tokens[first - 1]),
+        };
+
+        let mut relexed = tokens[..first].to_vec();
+        self.seek(start);
+
+        loop {
+            let index = self.index();
+
+            // once the lexer reaches the start of an old token after the edit, the rest
+            // of the tokens are the same as before, just moved
+            if index >= edit.new_end() {
+                let old_index = (index as isize - edit.delta()) as usize;
+
+                let next = match old_index {
+                    0 => 0,
+                    _ => tokens.partition_point(|token| full_end(token) < old_index) + 1,
+                };
+
+                let is_start =
+                    next <= tokens.len() && (next == 0 || full_end(&tokens[next - 1]) == old_index);
+
+                // in lossless mode the old end of file is not a boundary, the lexer
+                // still has to lex the new `Eof` token
+                let is_end = lossless && next == tokens.len();
+
+                if is_start && !is_end && next >= first {
+                    let rest = tokens[next..].iter();
+                    relexed.extend(rest.map(|token| shift_token(token, edit.delta())));
+
+                    break;
+                }
+            }
+
+            match self.next() {
+                Some(token) if token.kind == TokenKind::Eof => {
+                    relexed.push(token);
+                    break;
+                }
+                Some(token) => relexed.push(token),
+                None => break,
+            }
+        }
+
+        relexed
+    }
+}
+
+/// Moves errors after an edit, errors without a span stay as they are.
+fn shift_errors<'a>(errors: impl IntoIterator<Item = &'a Error>, delta: isize) -> Vec<Error> {
+    let shift = |error: &Error| {
+        let mut error = error.clone();
+
+        if !error.span().is_null() {
+            error.set_span(error.span().shifted(delta));
+        }
+
+        error
+    };
+
+    errors.into_iter().map(shift).collect()
+}
+
+/// Whether the parser is in the same state at `new` as it was at `old`, after an
+/// edit moved the source after `old` by `delta` bytes.
+fn is_same_state(old: &Checkpoint, new: &Checkpoint, delta: isize) -> bool {
+    let is_same_tokens = old.tokens.len() == new.tokens.len()
+        && (old.tokens.iter())
+            .zip(&new.tokens)
+            .all(|(old, new)| shift_token(old, delta) == *new);
+
+    is_same_tokens
+        && old.lexed as isize + delta == new.lexed as isize
+        && old.depth == new.depth
+        && old.expected == new.expected
+}
+
+/// Moves identifiers after an edit.
+fn shift_idents(idents: &[(String, Span)], delta: isize) -> Vec<(String, Span)> {
+    let shift = |(ident, span): &(String, Span)| (ident.clone(), span.shifted(delta));
+    idents.iter().map(shift).collect()
+}
+
+/// A parsed [`Program`] with its errors, which can be parsed again after an edit,
+/// reusing the top level declarations the edit didn't touch.
+///
+/// Identifiers are checked for confusables once the entire program is parsed, as
+/// an edit can change which identifier is seen first, so those warnings come after
+/// the other errors and warnings.
+#[derive(Clone, Debug)]
+pub struct ParsedProgram {
+    pub program: Program,
+    pub errors: Errors,
+    source_id: SourceId,
+    /// The state of the parser before each declaration in `program`.
+    checkpoints: Vec<Checkpoint>,
+    /// The identifiers in the order they were lexed.
+    idents: Vec<(String, Span)>,
+    /// The number of warnings reported while parsing, before the warnings about
+    /// identifiers.
+    warnings: usize,
+}
+
+impl ParsedProgram {
+    pub fn parse(source: &Source, source_id: SourceId) -> Self {
+        let mut parser = Parser::new(source, source_id).with_deferred_ident_checks();
+        let mut program = program_header(&mut parser);
+
+        let mut checkpoints = Vec::new();
+        program_decls(&mut parser, &mut program.decls, &mut checkpoints, |_| false);
+
+        let errors = parser.take_errors();
+        let idents = parser.take_idents();
+
+        Self::new(source, source_id, program, errors, checkpoints, idents)
+    }
+
+    /// Checks the identifiers of a parsed program, adding the warnings to `errors`.
+    fn new(
+        source: &Source,
+        source_id: SourceId,
+        program: Program,
+        mut errors: Errors,
+        checkpoints: Vec<Checkpoint>,
+        idents: Vec<(String, Span)>,
+    ) -> Self {
+        let warnings = errors.warnings().len();
+        let mut checker = IdentChecker::default();
+
+        for (ident, span) in &idents {
+            checker.check(ident, *span, source, &mut errors);
+        }
+
+        Self {
+            program,
+            errors,
+            source_id,
+            checkpoints,
+            idents,
+            warnings,
+        }
+    }
+
+    /// Parses `source`, the source after `edit` was applied to the source of `self`.
+    ///
+    /// Parsing resumes before the last declaration the lexer and parser hadn't looked
+    /// past before reaching the edit. Once the parser reaches a declaration after
+    /// the edit in the same state as before the edit, the rest of the declarations
+    /// and their errors are reused and moved. The result is the same as parsing the
+    /// entire source.
+    pub fn reparse(&self, source: &Source, edit: &TextEdit) -> Self {
+        let line_start = line_start(source, edit.range.start);
+
+        // an unterminated character literal on the line of the edit may have looked
+        // into the edit for its closing `'`
+        let is_before_edit = |checkpoint: &Checkpoint| {
+            let last = checkpoint.tokens.last().unwrap();
+            let line = source.source().get(line_start..checkpoint.lexed);
+
+            checkpoint.lexed + LOOKAHEAD <= edit.range.start
+                && !is_raw_string_prefix(last)
+                && !line.is_some_and(|line| line.contains('\''))
+        };
+
+        let reused = self.checkpoints.iter().rposition(is_before_edit);
+
+        let (mut parser, mut program) = match reused {
+            Some(index) => {
+                let checkpoint = &self.checkpoints[index];
+
+                let mut errors = Errors::new();
+
+                for error in &self.errors.errors()[..checkpoint.errors] {
+                    errors.push(error.clone());
+                }
+
+                for warning in &self.errors.warnings()[..checkpoint.warnings] {
+                    errors.push_warning(warning.clone());
+                }
+
+                let idents = self.idents[..checkpoint.idents].to_vec();
+                let parser = Parser::resume(source, self.source_id, checkpoint, errors, idents);
+
+                let program = Program {
+                    decls: self.program.decls[..index].to_vec(),
+                    docs: self.program.docs.clone(),
+                    attributes: self.program.attributes.clone(),
+                };
+
+                (parser, program)
+            }
+            None => {
+                let mut parser = Parser::new(source, self.source_id).with_deferred_ident_checks();
+                let program = program_header(&mut parser);
+
+                (parser, program)
+            }
+        };
+        let mut checkpoints = self.checkpoints[..reused.unwrap_or(0)].to_vec();
+        let delta = edit.delta();
+
+        // the old checkpoint the parser caught up with, after the edit
+        let mut caught_up = None;
+        program_decls(
+            &mut parser,
+            &mut program.decls,
+            &mut checkpoints,
+            |checkpoint| {
+                let start = checkpoint.tokens[0].span.index;
+
+                if start < edit.new_end() {
+                    return false;
+                }
+
+                let old_start = (start as isize - delta) as usize;
+                let Ok(index) = self
+                    .checkpoints
+                    .binary_search_by_key(&old_start, |old| old.tokens[0].span.index)
+                else {
+                    return false;
+                };
+
+                let is_same = is_same_state(&self.checkpoints[index], checkpoint, delta);
+
+                if is_same {
+                    caught_up = Some((index, checkpoint.clone()));
+                }
+
+                is_same
+            },
+        );
+
+        let mut errors = parser.take_errors();
+        let mut idents = parser.take_idents();
+
+        if let Some((index, caught_up)) = caught_up {
+            let old = &self.checkpoints[index];
+
+            for mut decl in self.program.decls[index..].iter().cloned() {
+                decl.shift(delta);
+                program.decls.push(decl);
+            }
+
+            // the token before the first reused declaration may have been edited, so
+            // the new checkpoint is kept for it
+            let (error_count, warning_count) = (caught_up.errors, caught_up.warnings);
+            let ident_count = caught_up.idents;
+            checkpoints.push(caught_up);
+
+            for checkpoint in &self.checkpoints[index + 1..] {
+                let mut checkpoint = checkpoint.clone();
+
+                for token in checkpoint.previous.iter_mut().chain(&mut checkpoint.tokens) {
+                    *token = shift_token(token, delta);
+                }
+
+                checkpoint.lexed = (checkpoint.lexed as isize + delta) as usize;
+                checkpoint.errors = checkpoint.errors - old.errors + error_count;
+                checkpoint.warnings = checkpoint.warnings - old.warnings + warning_count;
+                checkpoint.idents = checkpoint.idents - old.idents + ident_count;
+                checkpoints.push(checkpoint);
+            }
+
+            let old_errors = &self.errors.errors()[old.errors..];
+            let old_warnings = &self.errors.warnings()[old.warnings..self.warnings];
+
+            for error in shift_errors(old_errors, delta) {
+                errors.push(error);
+            }
+
+            for warning in shift_errors(old_warnings, delta) {
+                errors.push_warning(warning);
+            }
+
+            idents.extend(shift_idents(&self.idents[old.idents..], delta));
+        }
+
+        Self::new(source, self.source_id, program, errors, checkpoints, idents)
+    }
+}
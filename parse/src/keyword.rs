@@ -15,37 +15,61 @@ pub enum Keyword {
     Let,
     Loop,
     Match,
+    Mod,
     Pub,
     Return,
     Self_,
     Static,
     Super,
+    True,
+    Use,
+    While,
 }
 
 impl Keyword {
-    pub fn from_str(s: &str) -> Option<Self> {
+    /// Words that are not keywords yet but may become keywords, and can't be used as
+    /// identifiers without the raw `r#` prefix.
+    pub const RESERVED: &'static [&'static str] = &[
+        "abstract", "async", "await", "do", "dyn", "extern", "final", "impl", "macro", "move",
+        "mut", "override", "priv", "trait", "try", "type", "typeof", "unsafe", "virtual", "where",
+        "yield",
+    ];
+
+    pub fn is_reserved(s: &str) -> bool {
+        Self::RESERVED.contains(&s)
+    }
+}
+
+impl std::str::FromStr for Keyword {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "as" => Some(Keyword::As),
-            "break" => Some(Keyword::Break),
-            "class" => Some(Keyword::Class),
-            "const" => Some(Keyword::Const),
-            "continue" => Some(Keyword::Continue),
-            "else" => Some(Keyword::Else),
-            "enum" => Some(Keyword::Enum),
-            "false" => Some(Keyword::False),
-            "fn" => Some(Keyword::Fn),
-            "for" => Some(Keyword::For),
-            "if" => Some(Keyword::If),
-            "in" => Some(Keyword::In),
-            "let" => Some(Keyword::Let),
-            "loop" => Some(Keyword::Loop),
-            "match" => Some(Keyword::Match),
-            "pub" => Some(Keyword::Pub),
-            "return" => Some(Keyword::Return),
-            "self" => Some(Keyword::Self_),
-            "static" => Some(Keyword::Static),
-            "super" => Some(Keyword::Super),
-            _ => None,
+            "as" => Ok(Keyword::As),
+            "break" => Ok(Keyword::Break),
+            "class" => Ok(Keyword::Class),
+            "const" => Ok(Keyword::Const),
+            "continue" => Ok(Keyword::Continue),
+            "else" => Ok(Keyword::Else),
+            "enum" => Ok(Keyword::Enum),
+            "false" => Ok(Keyword::False),
+            "fn" => Ok(Keyword::Fn),
+            "for" => Ok(Keyword::For),
+            "if" => Ok(Keyword::If),
+            "in" => Ok(Keyword::In),
+            "let" => Ok(Keyword::Let),
+            "loop" => Ok(Keyword::Loop),
+            "match" => Ok(Keyword::Match),
+            "mod" => Ok(Keyword::Mod),
+            "pub" => Ok(Keyword::Pub),
+            "return" => Ok(Keyword::Return),
+            "self" => Ok(Keyword::Self_),
+            "static" => Ok(Keyword::Static),
+            "super" => Ok(Keyword::Super),
+            "true" => Ok(Keyword::True),
+            "use" => Ok(Keyword::Use),
+            "while" => Ok(Keyword::While),
+            _ => Err(()),
         }
     }
 }
@@ -68,11 +92,38 @@ impl std::fmt::Display for Keyword {
             Keyword::Let => write!(f, "let"),
             Keyword::Loop => write!(f, "loop"),
             Keyword::Match => write!(f, "match"),
+            Keyword::Mod => write!(f, "mod"),
             Keyword::Pub => write!(f, "pub"),
             Keyword::Return => write!(f, "return"),
             Keyword::Self_ => write!(f, "self"),
             Keyword::Static => write!(f, "static"),
             Keyword::Super => write!(f, "super"),
+            Keyword::True => write!(f, "true"),
+            Keyword::Use => write!(f, "use"),
+            Keyword::While => write!(f, "while"),
         }
     }
 }
+
+/// Words that are keywords only in specific positions, and identifiers everywhere else.
+///
+/// Contextual keywords are lexed as [`TokenKind::Ident`](crate::token::TokenKind::Ident),
+/// and the parser checks for them where they are meaningful.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContextualKeyword {
+    Crate,
+}
+
+impl ContextualKeyword {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContextualKeyword::Crate => "crate",
+        }
+    }
+}
+
+impl std::fmt::Display for ContextualKeyword {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1,19 +1,41 @@
+use std::collections::HashMap;
+
 use athame::{
-    error::Error,
+    error::{Error, Errors},
+    float::Float,
+    int::Int,
     sources::{Source, SourceId},
     span::Span,
 };
 
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+use unicode_security::{skeleton, MixedScript};
+
 use crate::{
     keyword::Keyword,
     symbol::Symbol,
-    token::{Token, TokenKind},
+    token::{
+        DocComment, DocStyle, FloatLiteral, IntLiteral, Radix, Token, TokenKind, Trivia, TriviaKind,
+    },
 };
 
+/// The type suffix of a number literal, e.g. `u8` in `10u8`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Suffix {
+    Int(Int),
+    Float(Float),
+}
+
 pub struct Lexer<'a> {
     source: &'a Source,
     source_id: SourceId,
     index: usize,
+    lossless: bool,
+    is_done: bool,
+    errors: Errors,
+    idents: IdentChecker,
+    /// The identifiers lexed so far, when checking them is left to the caller.
+    deferred_idents: Option<Vec<(String, Span)>>,
 }
 
 impl<'a> Lexer<'a> {
@@ -22,13 +44,66 @@ impl<'a> Lexer<'a> {
             source,
             source_id,
             index: 0,
+            lossless: false,
+            is_done: false,
+            errors: Errors::new(),
+            idents: IdentChecker::default(),
+            deferred_idents: None,
         }
     }
 
+    /// In lossless mode every token carries its surrounding whitespace and comments
+    /// as trivia, and [`Lexer::tokenize`] ends with a [`TokenKind::Eof`] token holding
+    /// the trivia at the end of the file.
+    ///
+    /// Joining the tokens with [`join_tokens`] reproduces the source exactly.
+    pub fn with_lossless(mut self, lossless: bool) -> Self {
+        self.lossless = lossless;
+        self
+    }
+
+    pub fn is_lossless(&self) -> bool {
+        self.lossless
+    }
+
+    /// Collects identifiers to be checked with an [`IdentChecker`] later, instead of
+    /// checking them as they are lexed, see [`Lexer::take_idents`].
+    pub(crate) fn with_deferred_ident_checks(mut self) -> Self {
+        self.deferred_idents = Some(Vec::new());
+        self
+    }
+
+    /// Takes the identifiers lexed since the last call, if checking them is deferred.
+    pub(crate) fn take_idents(&mut self) -> Vec<(String, Span)> {
+        self.deferred_idents
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    pub fn source(&self) -> &'a Source {
+        self.source
+    }
+
+    /// The errors reported so far, lexing continues past every error.
+    pub fn errors(&self) -> &Errors {
+        &self.errors
+    }
+
+    pub fn take_errors(&mut self) -> Errors {
+        std::mem::take(&mut self.errors)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.remaining().is_empty()
     }
 
+    /// Moves the lexer to `index`, which must be the start of a token or of trivia.
+    pub fn seek(&mut self, index: usize) {
+        self.index = index;
+        self.is_done = false;
+    }
+
     pub fn remaining(&self) -> &'a str {
         &self.source.source()[self.index..]
     }
@@ -37,27 +112,46 @@ impl<'a> Lexer<'a> {
         self.index
     }
 
-    pub fn span(&self, end: usize) -> Span {
-        Span::new(self.index, end - self.index, self.source_id)
+    pub fn span(&self, start: usize) -> Span {
+        Span::new(start, self.index - start, self.source_id)
     }
 
-    pub fn next(&mut self) -> Option<char> {
-        let c = self.remaining().chars().next()?;
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
         self.index += c.len_utf8();
         Some(c)
     }
 
+    /// Peeks the next char, ASCII is decoded without walking the remaining input.
     pub fn peek(&self) -> Option<char> {
-        self.remaining().chars().next()
+        self.peek_at(self.index)
     }
 
+    /// Peeks `n` chars ahead, the lexer never looks further ahead than a few chars.
     pub fn peek_nth(&self, n: usize) -> Option<char> {
-        self.remaining().chars().nth(n)
+        let mut index = self.index;
+
+        for _ in 0..n {
+            index += self.peek_at(index)?.len_utf8();
+        }
+
+        self.peek_at(index)
+    }
+
+    fn peek_at(&self, index: usize) -> Option<char> {
+        let source = self.source.source();
+        let byte = *source.as_bytes().get(index)?;
+
+        if byte.is_ascii() {
+            Some(byte as char)
+        } else {
+            source[index..].chars().next()
+        }
     }
 
     pub fn take2(&mut self) {
-        self.next();
-        self.next();
+        self.next_char();
+        self.next_char();
     }
 
     pub fn take_whitespace(&mut self) -> &'a str {
@@ -68,127 +162,1048 @@ impl<'a> Lexer<'a> {
                 break;
             }
 
-            self.next();
+            self.next_char();
         }
 
         &self.source.source()[index..self.index]
     }
 
-    pub fn lex_integer(&mut self, radix: u32) -> Result<u64, Error> {
-        let mut value = 0;
-        let mut digits = 0;
+    /// Takes whitespace up to, but not including, the next line break.
+    pub fn take_inline_whitespace(&mut self) -> &'a str {
+        let index = self.index();
 
         while let Some(c) = self.peek() {
-            let digit = match c.to_digit(radix) {
-                Some(digit) => digit,
-                None => break,
+            if !c.is_whitespace() || c == '\n' {
+                break;
+            }
+
+            self.next_char();
+        }
+
+        &self.source.source()[index..self.index]
+    }
+
+    fn is_doc_comment(&self) -> bool {
+        let remaining = self.remaining();
+
+        remaining.starts_with("//!")
+            || remaining.starts_with("///") && !remaining.starts_with("////")
+    }
+
+    pub fn take_line_comment(&mut self) -> &'a str {
+        let index = self.index();
+
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                break;
+            }
+
+            self.next_char();
+        }
+
+        &self.source.source()[index..self.index]
+    }
+
+    /// Takes a block comment starting at the `/*`, block comments may be nested.
+    pub fn take_block_comment(&mut self) -> Result<&'a str, Error> {
+        let index = self.index();
+        let mut depth = 0;
+
+        loop {
+            if self.remaining().starts_with("/*") {
+                self.take2();
+                depth += 1;
+            } else if self.remaining().starts_with("*/") {
+                self.take2();
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            } else if self.next_char().is_none() {
+                let err = Error::new("unterminated block comment")
+                    .with_span(Span::new(index, 2, self.source_id))
+                    .with_note(format!("{} comment(s) left unclosed", depth));
+
+                return Err(err);
+            }
+        }
+
+        Ok(&self.source.source()[index..self.index])
+    }
+
+    /// Takes whitespace and comments, but not doc comments, which are lexed as tokens.
+    pub fn take_trivia(&mut self) -> &'a str {
+        let index = self.index();
+
+        loop {
+            self.take_whitespace();
+
+            if self.remaining().starts_with("//") && !self.is_doc_comment() {
+                self.take_line_comment();
+            } else if self.remaining().starts_with("/*") {
+                if let Err(err) = self.take_block_comment() {
+                    self.errors.push(err);
+                }
+            } else {
+                break;
+            }
+        }
+
+        &self.source.source()[index..self.index]
+    }
+
+    /// Lexes trivia as a list of pieces, trailing trivia stops at the end of the line.
+    pub fn lex_trivia(&mut self, trailing: bool) -> Vec<Trivia> {
+        let mut trivia = Vec::new();
+
+        loop {
+            let start = self.index();
+
+            let kind = if self.remaining().starts_with("//") && !self.is_doc_comment() {
+                self.take_line_comment();
+                TriviaKind::LineComment
+            } else if self.remaining().starts_with("/*") {
+                if let Err(err) = self.take_block_comment() {
+                    self.errors.push(err);
+                }
+
+                TriviaKind::BlockComment
+            } else {
+                let whitespace = match trailing {
+                    true => self.take_inline_whitespace(),
+                    false => self.take_whitespace(),
+                };
+
+                if whitespace.is_empty() {
+                    break;
+                }
+
+                TriviaKind::Whitespace
             };
 
-            value = value * radix as u64 + digit as u64;
-            digits += 1;
+            trivia.push(Trivia::new(kind, self.span(start)));
+        }
+
+        trivia
+    }
+
+    pub fn lex_doc_comment(&mut self) -> DocComment {
+        let style = if self.remaining().starts_with("//!") {
+            DocStyle::Inner
+        } else {
+            DocStyle::Outer
+        };
+
+        self.take2();
+        self.next_char();
+
+        DocComment {
+            style,
+            text: String::from(self.take_line_comment()),
+        }
+    }
+
+    pub fn lex_digits(&mut self, radix: Radix) -> Result<String, Error> {
+        let start = self.index();
+        let mut digits = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '_' {
+                self.next_char();
+                continue;
+            }
+
+            if !c.is_digit(radix.value()) {
+                if c.is_ascii_digit() {
+                    let start = self.index();
+                    self.next_char();
 
-            self.next();
+                    let err = Error::new(format!(
+                        "invalid digit '{}' in base {} literal",
+                        c,
+                        radix.value()
+                    ))
+                    .with_span(self.span(start));
+
+                    return Err(err);
+                }
+
+                break;
+            }
+
+            digits.push(c);
+            self.next_char();
         }
 
-        if digits == 0 {
+        if digits.is_empty() {
             let err = Error::new(format!(
-                "expected at least one digit in base {} integer",
-                radix
+                "expected at least one digit in base {} literal",
+                radix.value()
             ))
-            .with_span(self.span(self.index));
+            .with_span(self.span(start));
 
             return Err(err);
         }
 
-        Ok(value)
+        Ok(digits)
+    }
+
+    fn is_exponent(&self) -> bool {
+        if !matches!(self.peek(), Some('e' | 'E')) {
+            return false;
+        }
+
+        match self.peek_nth(1) {
+            Some('+' | '-') => matches!(self.peek_nth(2), Some(c) if c.is_ascii_digit()),
+            Some(c) => c.is_ascii_digit(),
+            None => false,
+        }
+    }
+
+    pub fn lex_suffix(&mut self) -> Result<Option<Suffix>, Error> {
+        let suffix_start = self.index();
+
+        if !matches!(self.peek(), Some(c) if is_ident_start(c)) {
+            return Ok(None);
+        }
+
+        let suffix = self.lex_ident()?;
+
+        let suffix = match suffix.as_str() {
+            "i8" => Suffix::Int(Int::i8()),
+            "i16" => Suffix::Int(Int::i16()),
+            "i32" => Suffix::Int(Int::i32()),
+            "i64" => Suffix::Int(Int::i64()),
+            "i128" => Suffix::Int(Int::i128()),
+            "isize" => Suffix::Int(Int::isize()),
+            "u8" => Suffix::Int(Int::u8()),
+            "u16" => Suffix::Int(Int::u16()),
+            "u32" => Suffix::Int(Int::u32()),
+            "u64" => Suffix::Int(Int::u64()),
+            "u128" => Suffix::Int(Int::u128()),
+            "usize" => Suffix::Int(Int::usize()),
+            "f16" => Suffix::Float(Float::f16()),
+            "f32" => Suffix::Float(Float::f32()),
+            "f64" => Suffix::Float(Float::f64()),
+            _ => {
+                let span = self.span(suffix_start);
+
+                let err = Error::new(format!("invalid suffix '{}' for number literal", suffix))
+                    .with_span(span)
+                    .with_note("valid suffixes are 'i8'..'i128', 'isize', 'u8'..'u128', 'usize', 'f16', 'f32' and 'f64'");
+
+                return Err(err);
+            }
+        };
+
+        Ok(Some(suffix))
     }
 
     pub fn lex_number(&mut self) -> Result<TokenKind, Error> {
-        let mut radix = 10;
+        let start = self.index();
+        let mut radix = Radix::Decimal;
 
         if self.remaining().starts_with("0x") {
             self.take2();
-            radix = 16;
+            radix = Radix::Hexadecimal;
         } else if self.remaining().starts_with("0o") {
             self.take2();
-            radix = 8;
+            radix = Radix::Octal;
         } else if self.remaining().starts_with("0b") {
             self.take2();
-            radix = 2;
+            radix = Radix::Binary;
+        }
+
+        // `.5` is shorthand for `0.5`, but `0x.5` is missing its digits
+        let integer = if self.index() == start && self.peek() == Some('.') {
+            String::from("0")
+        } else {
+            self.lex_digits(radix)?
+        };
+
+        let mut is_float = false;
+        let mut float = integer.clone();
+
+        if radix == Radix::Decimal
+            && self.peek() == Some('.')
+            && matches!(self.peek_nth(1), Some(c) if c.is_ascii_digit())
+        {
+            self.next_char();
+
+            is_float = true;
+            float.push('.');
+            float.push_str(&self.lex_digits(radix)?);
+        }
+
+        if radix == Radix::Decimal && self.is_exponent() {
+            float.push(self.next_char().unwrap());
+
+            if let Some(sign @ ('+' | '-')) = self.peek() {
+                self.next_char();
+                float.push(sign);
+            }
+
+            is_float = true;
+            float.push_str(&self.lex_digits(radix)?);
+        }
+
+        let suffix = self.lex_suffix()?;
+        let span = self.span(start);
+
+        match suffix {
+            Some(Suffix::Float(suffix)) => {
+                if radix != Radix::Decimal {
+                    let err = Error::new(format!(
+                        "base {} float literals are not supported",
+                        radix.value()
+                    ))
+                    .with_span(span);
+
+                    return Err(err);
+                }
+
+                Ok(TokenKind::Float(lex_float(&float, Some(suffix), span)?))
+            }
+            Some(Suffix::Int(suffix)) if is_float => {
+                let err = Error::new(format!("invalid suffix '{}' for float literal", suffix))
+                    .with_span(span)
+                    .with_note("float literals can only have the suffixes 'f16', 'f32' and 'f64'");
+
+                Err(err)
+            }
+            None if is_float => Ok(TokenKind::Float(lex_float(&float, None, span)?)),
+            suffix => {
+                let suffix = match suffix {
+                    Some(Suffix::Int(int)) => Some(int),
+                    _ => None,
+                };
+
+                Ok(TokenKind::Int(lex_int(&integer, radix, suffix, span)?))
+            }
+        }
+    }
+
+    /// Lexes an escape sequence, starting at the `\\`.
+    ///
+    /// Byte literals accept `\\x` escapes up to `\\xff`, but no `\\u{..}` escapes.
+    pub fn lex_escape(&mut self, is_byte: bool) -> Result<char, Error> {
+        let start = self.index();
+        self.next_char();
+
+        let Some(c) = self.next_char() else {
+            let err = Error::new("unterminated escape sequence").with_span(self.span(start));
+            return Err(err);
+        };
+
+        let escaped = match c {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '\\' => '\\',
+            '0' => '\0',
+            '\'' => '\'',
+            '"' => '"',
+            'x' => {
+                let mut value = 0;
+
+                for _ in 0..2 {
+                    let digit = self.peek().and_then(|c| c.to_digit(16));
+
+                    let Some(digit) = digit else {
+                        let err = Error::new("invalid escape sequence")
+                            .with_span(self.span(start))
+                            .with_note("'\\x' escapes must be followed by exactly two hex digits");
+
+                        return Err(err);
+                    };
+
+                    value = value * 16 + digit;
+                    self.next_char();
+                }
+
+                if value > 0x7f && !is_byte {
+                    let err = Error::new("out of range hex escape")
+                        .with_span(self.span(start))
+                        .with_note("'\\x' escapes must be in the range '\\x00'..='\\x7f'");
+
+                    return Err(err);
+                }
+
+                char::from_u32(value).unwrap()
+            }
+            'u' => {
+                if is_byte {
+                    let err = Error::new("unicode escape in byte literal")
+                        .with_span(self.span(start))
+                        .with_note("byte literals can only contain ASCII characters");
+
+                    return Err(err);
+                }
+
+                if self.peek() != Some('{') {
+                    let err = Error::new("invalid unicode escape")
+                        .with_span(self.span(start))
+                        .with_note("unicode escapes must be of the form '\\u{1F600}'");
+
+                    return Err(err);
+                }
+
+                self.next_char();
+
+                let mut value: u32 = 0;
+                let mut digits = 0;
+
+                while let Some(c) = self.peek() {
+                    if c == '}' {
+                        break;
+                    }
+
+                    let Some(digit) = c.to_digit(16) else {
+                        break;
+                    };
+
+                    value = value.saturating_mul(16).saturating_add(digit);
+                    digits += 1;
+                    self.next_char();
+                }
+
+                if self.peek() != Some('}') || digits == 0 || digits > 6 {
+                    if self.peek() == Some('}') {
+                        self.next_char();
+                    }
+
+                    let err = Error::new("invalid unicode escape")
+                        .with_span(self.span(start))
+                        .with_note("unicode escapes must contain between one and six hex digits");
+
+                    return Err(err);
+                }
+
+                self.next_char();
+
+                match char::from_u32(value) {
+                    Some(c) => c,
+                    None => {
+                        let err = Error::new("invalid unicode character escape")
+                            .with_span(self.span(start))
+                            .with_note(format!(
+                                "'{:x}' is not a valid unicode scalar value",
+                                value
+                            ));
+
+                        return Err(err);
+                    }
+                }
+            }
+            _ => {
+                let err = Error::new(format!("unknown character escape '{}'", c.escape_default()))
+                    .with_span(self.span(start));
+
+                return Err(err);
+            }
+        };
+
+        Ok(escaped)
+    }
+
+    /// Lexes the contents of a string literal, starting after the opening quote
+    /// and ending after the closing quote.
+    fn lex_quoted(&mut self, start: usize, is_byte: bool) -> Result<String, Error> {
+        let mut value = String::new();
+
+        loop {
+            let Some(c) = self.peek() else {
+                let err = Error::new("unterminated string literal").with_span(self.span(start));
+                return Err(err);
+            };
+
+            match c {
+                '"' => {
+                    self.next_char();
+                    break;
+                }
+                '\\' if self.peek_nth(1) == Some('\n') => {
+                    // a backslash at the end of a line skips the line break
+                    // and the leading whitespace of the next line
+                    self.next_char();
+                    self.take_whitespace();
+                }
+                '\\' => match self.lex_escape(is_byte) {
+                    Ok(c) => value.push(c),
+                    Err(err) => self.errors.push(err),
+                },
+                _ => {
+                    if let Err(err) = self.lex_byte_char(c, is_byte) {
+                        self.errors.push(err);
+                    }
+
+                    value.push(c);
+                }
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Lexes a raw string, starting at the `r`.
+    fn lex_raw_string(&mut self, is_byte: bool) -> Result<String, Error> {
+        let start = self.index();
+        self.next_char();
+
+        let mut hashes = 0;
+        while self.peek() == Some('#') {
+            hashes += 1;
+            self.next_char();
+        }
+
+        if self.next_char() != Some('"') {
+            let err = Error::new("expected '\"' in raw string literal").with_span(self.span(start));
+            return Err(err);
+        }
+
+        let content_start = self.index();
+
+        loop {
+            let Some(c) = self.next_char() else {
+                let err = Error::new("unterminated raw string literal")
+                    .with_span(self.span(start))
+                    .with_note(format!(
+                        "the literal must be terminated by '\"{}'",
+                        "#".repeat(hashes)
+                    ));
+
+                return Err(err);
+            };
+
+            if is_byte && !c.is_ascii() {
+                let start = self.index() - c.len_utf8();
+
+                let err = Error::new("non-ASCII character in raw byte string literal")
+                    .with_span(self.span(start));
+
+                self.errors.push(err);
+            }
+
+            if c == '"' && self.remaining().chars().take_while(|&c| c == '#').count() >= hashes {
+                let content = &self.source.source()[content_start..self.index - 1];
+
+                for _ in 0..hashes {
+                    self.next_char();
+                }
+
+                return Ok(String::from(content));
+            }
+        }
+    }
+
+    fn lex_char(&mut self, is_byte: bool) -> Result<char, Error> {
+        let start = self.index();
+        self.next_char();
+
+        let c = match self.peek() {
+            None | Some('\n') => {
+                let err = Error::new("unterminated character literal").with_span(self.span(start));
+                return Err(err);
+            }
+            Some('\'') => {
+                self.next_char();
+
+                let err = Error::new("empty character literal").with_span(self.span(start));
+                return Err(err);
+            }
+            Some('\\') => match self.lex_escape(is_byte) {
+                Ok(c) => c,
+                Err(err) => {
+                    self.errors.push(err);
+                    char::REPLACEMENT_CHARACTER
+                }
+            },
+            Some(c) => {
+                if let Err(err) = self.lex_byte_char(c, is_byte) {
+                    self.errors.push(err);
+                }
+
+                c
+            }
+        };
+
+        if self.peek() == Some('\'') {
+            self.next_char();
+            return Ok(c);
+        }
+
+        let line = self.remaining().split('\n').next().unwrap();
+
+        if let Some(end) = line.find('\'') {
+            self.index += end + 1;
+
+            let err = Error::new("character literal may only contain one codepoint")
+                .with_span(self.span(start));
+
+            return Err(err);
+        }
+
+        let err = Error::new("unterminated character literal").with_span(self.span(start));
+        Err(err)
+    }
+
+    /// Takes a single unescaped character of a literal, checking that byte
+    /// literals only contain ASCII.
+    fn lex_byte_char(&mut self, c: char, is_byte: bool) -> Result<(), Error> {
+        let start = self.index();
+        self.next_char();
+
+        if is_byte && !c.is_ascii() {
+            let err = Error::new("non-ASCII character in byte literal")
+                .with_span(self.span(start))
+                .with_note("use a '\\x' escape to write non-ASCII bytes");
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Lexes a string, char or byte literal.
+    ///
+    /// Errors inside the literal, like invalid escapes, are reported without ending
+    /// the literal, which is then lexed as [`TokenKind::Error`].
+    pub fn lex_string(&mut self) -> Result<TokenKind, Error> {
+        let errors = self.errors.len();
+        let kind = self.lex_string_kind()?;
+
+        if self.errors.len() > errors {
+            return Ok(TokenKind::Error);
+        }
+
+        Ok(kind)
+    }
+
+    fn lex_string_kind(&mut self) -> Result<TokenKind, Error> {
+        let remaining = self.remaining();
+
+        if remaining.starts_with("b'") {
+            self.next_char();
+            return Ok(TokenKind::Byte(self.lex_char(true)? as u8));
         }
 
-        let value = self.lex_integer(radix)?;
+        if remaining.starts_with("b\"") {
+            let start = self.index();
+            self.take2();
+
+            let value = self.lex_quoted(start, true)?;
+            return Ok(TokenKind::ByteString(
+                value.chars().map(|c| c as u8).collect(),
+            ));
+        }
 
-        if self.peek() == Some('.') && radix == 10 {
-            self.next();
+        if remaining.starts_with("br") {
+            self.next_char();
+            let value = self.lex_raw_string(true)?;
+            return Ok(TokenKind::ByteString(value.into_bytes()));
+        }
 
-            let decimal = self.lex_integer(10)?;
-            let digits = decimal.to_string().len() as i32;
+        if remaining.starts_with('r') {
+            return Ok(TokenKind::String(self.lex_raw_string(false)?));
+        }
 
-            let value = value as f64 + decimal as f64 / f64::powi(10.0, digits);
+        if remaining.starts_with('\'') {
+            return Ok(TokenKind::Char(self.lex_char(false)?));
         }
 
-        todo!()
+        let start = self.index();
+        self.next_char();
+
+        Ok(TokenKind::String(self.lex_quoted(start, false)?))
     }
 
+    fn is_string(&self) -> bool {
+        let mut chars = self.remaining().chars();
+
+        let is_raw = |mut chars: std::str::Chars| chars.find(|&c| c != '#') == Some('"');
+
+        match chars.next() {
+            Some('"' | '\'') => true,
+            Some('b') => match chars.next() {
+                Some('"' | '\'') => true,
+                Some('r') => is_raw(chars),
+                _ => false,
+            },
+            Some('r') => is_raw(chars),
+            _ => false,
+        }
+    }
+
+    /// Lexes an identifier following UAX #31, normalized to NFC.
     pub fn lex_ident(&mut self) -> Result<String, Error> {
-        let mut ident = String::new();
+        let start = self.index();
 
         while let Some(c) = self.peek() {
-            if !c.is_alphanumeric() && c != '_' {
+            if !is_ident_continue(c) {
                 break;
             }
 
-            ident.push(c);
-            self.next();
+            self.next_char();
+        }
+
+        let ident = &self.source.source()[start..self.index];
+
+        if ident.is_ascii() || is_nfc(ident) {
+            Ok(String::from(ident))
+        } else {
+            Ok(ident.nfc().collect())
         }
+    }
+
+    /// Whether the source continues with a label, `'a` is a label while `'a'` is a
+    /// character literal.
+    fn is_label(&self) -> bool {
+        self.peek() == Some('\'')
+            && matches!(self.peek_nth(1), Some(c) if is_ident_start(c))
+            && self.peek_nth(2) != Some('\'')
+    }
 
-        Ok(ident)
+    fn is_raw_ident(&self) -> bool {
+        self.remaining().starts_with("r#")
+            && matches!(self.peek_nth(2), Some(c) if is_ident_start(c))
     }
 
-    pub fn lex_symbol(&mut self, c: char) -> Result<Symbol, Error> {
-        if let Some(symbol) = Symbol::from_parts(c, self.peek_nth(1)) {
+    /// Lexes a raw identifier `r#ident`, which may be a keyword or a reserved word.
+    fn lex_raw_ident(&mut self) -> Result<TokenKind, Error> {
+        let start = self.index();
+
+        self.take2();
+        let ident = self.lex_ident()?;
+
+        // these are keywords even when written as raw identifiers
+        if matches!(ident.as_str(), "self" | "super" | "crate") {
+            let error = Error::new(format!("'{}' cannot be a raw identifier", ident))
+                .with_span(self.span(start));
+
+            return Err(error);
+        }
+
+        self.check_ident(&ident, self.span(start));
+
+        Ok(TokenKind::Ident(ident))
+    }
+
+    fn check_ident(&mut self, ident: &str, span: Span) {
+        match self.deferred_idents {
+            Some(ref mut idents) => idents.push((String::from(ident), span)),
+            None => self
+                .idents
+                .check(ident, span, self.source, &mut self.errors),
+        }
+    }
+
+    /// Lexes the longest symbol at the current position.
+    pub fn lex_symbol(&mut self) -> Result<Symbol, Error> {
+        let start = self.index();
+
+        if let Some(symbol) = Symbol::from_prefix(self.remaining()) {
+            self.index += symbol.as_str().len();
             return Ok(symbol);
         }
 
-        Err(Error::new(format!("invalid character '{}'", c)).with_span(self.span(self.index + 1)))
+        let c = self.next_char().unwrap();
+
+        Err(Error::new(format!("invalid character '{}'", c)).with_span(self.span(start)))
     }
 
     pub fn lex_kind(&mut self) -> Result<TokenKind, Error> {
-        let c = self.next().unwrap();
+        let c = self.peek().unwrap();
+
+        if self.is_doc_comment() {
+            return Ok(TokenKind::DocComment(self.lex_doc_comment()));
+        }
+
+        if self.is_label() {
+            self.next_char();
+            return Ok(TokenKind::Label(self.lex_ident()?));
+        }
 
-        if c.is_alphabetic() || c == '_' {
+        if self.is_string() {
+            return self.lex_string();
+        }
+
+        if self.is_raw_ident() {
+            return self.lex_raw_ident();
+        }
+
+        if is_ident_start(c) {
+            let start = self.index();
             let ident = self.lex_ident()?;
 
-            if let Some(keyword) = Keyword::from_str(&ident) {
+            if let Ok(keyword) = ident.parse::<Keyword>() {
                 return Ok(TokenKind::Keyword(keyword));
+            }
+
+            if Keyword::is_reserved(&ident) {
+                let error = Error::new(format!("'{}' is a reserved word", ident))
+                    .with_span(self.span(start))
+                    .with_note(format!("use 'r#{}' to use it as an identifier", ident));
+
+                self.errors.push(error);
             } else {
-                return Ok(TokenKind::Ident(ident));
+                self.check_ident(&ident, self.span(start));
             }
+
+            return Ok(TokenKind::Ident(ident));
         }
 
-        if c.is_digit(10) {
-            return self.lex_number();
+        let is_number = c.is_ascii_digit()
+            || c == '.' && matches!(self.peek_nth(1), Some(c) if c.is_ascii_digit());
+
+        if is_number {
+            let number = self.lex_number();
+
+            if number.is_err() {
+                // skip the rest of the malformed literal
+                self.lex_ident()?;
+            }
+
+            return number;
         }
 
-        if c == '.' {
-            if let Some(c) = self.peek() {
-                if c.is_digit(10) {
-                    return self.lex_number();
+        Ok(TokenKind::Symbol(self.lex_symbol()?))
+    }
+
+    /// Lexes the next token, returning [`TokenKind::Eof`] at the end of the source.
+    ///
+    /// Errors are reported to [`Lexer::errors`] and lexed as a [`TokenKind::Error`]
+    /// token covering the invalid input.
+    pub fn lex(&mut self) -> Token {
+        let leading = match self.lossless {
+            true => self.lex_trivia(false),
+            false => {
+                self.take_trivia();
+                Vec::new()
+            }
+        };
+
+        let start = self.index();
+
+        if self.is_empty() {
+            let mut token = Token::new(TokenKind::Eof, self.span(start));
+            token.leading = leading;
+            return token;
+        }
+
+        let kind = match self.lex_kind() {
+            Ok(kind) => kind,
+            Err(err) => {
+                self.errors.push(err);
+
+                if self.index == start {
+                    self.next_char();
                 }
+
+                TokenKind::Error
             }
+        };
+
+        let mut token = Token::new(kind, self.span(start));
+        token.leading = leading;
+
+        if self.lossless {
+            token.trailing = self.lex_trivia(true);
         }
 
-        Ok(TokenKind::Symbol(self.lex_symbol(c)?))
+        token
     }
 
-    pub fn lex(&mut self) -> Result<Token, Error> {
-        self.take_whitespace();
+    /// Lexes the entire source, the [`TokenKind::Eof`] token is only included in lossless mode.
+    pub fn tokenize(&mut self) -> Vec<Token> {
+        self.collect()
+    }
+}
 
-        let start = self.index();
-        let kind = self.lex_kind()?;
-        let span = self.span(start);
+/// Lexes tokens on demand, ending at the end of the source.
+///
+/// The [`TokenKind::Eof`] token is only yielded in lossless mode.
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        let token = self.lex();
+
+        if token.kind == TokenKind::Eof {
+            self.is_done = true;
+
+            if !self.lossless {
+                return None;
+            }
+        }
+
+        Some(token)
+    }
+}
+
+/// Checks identifiers in the order they appear in a source, warning about identifiers
+/// mixing scripts and identifiers that can be confused with an earlier one.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct IdentChecker {
+    /// The identifiers seen so far, with the span they were first seen at.
+    idents: HashMap<String, Span>,
+    /// The confusable skeletons of the identifiers seen so far.
+    skeletons: HashMap<String, String>,
+}
+
+impl IdentChecker {
+    pub(crate) fn check(&mut self, ident: &str, span: Span, source: &Source, errors: &mut Errors) {
+        if self.idents.contains_key(ident) {
+            return;
+        }
+
+        if !ident.is_ascii() && !ident.is_single_script() {
+            let warning = Error::new(format!("identifier '{}' mixes multiple scripts", ident))
+                .with_span(span)
+                .with_note("identifiers mixing scripts can look like other identifiers");
+
+            errors.push_warning(warning);
+        }
+
+        let skeleton: String = skeleton(ident).collect();
+
+        if let Some(other) = self.skeletons.get(&skeleton) {
+            if !ident.is_ascii() || !other.is_ascii() {
+                let warning = Error::new(format!(
+                    "identifier '{}' is confusable with '{}'",
+                    ident, other
+                ))
+                .with_span(span);
+
+                // only resolve the position of the other identifier when warning, as
+                // finding the line is linear in the length of the source
+                let position = source.span(self.idents[other]);
+                let warning = warning.with_note(format!(
+                    "'{}' is used at {}:{}",
+                    other,
+                    position.line(),
+                    position.column()
+                ));
+
+                errors.push_warning(warning);
+            }
+        } else {
+            self.skeletons.insert(skeleton, String::from(ident));
+        }
+
+        self.idents.insert(String::from(ident), span);
+    }
+}
+
+/// Joins lossless tokens, including their trivia, back into source text.
+pub fn join_tokens(tokens: &[Token], source: &Source) -> String {
+    let mut text = String::new();
+
+    for token in tokens {
+        for trivia in &token.leading {
+            text.push_str(trivia.text(source));
+        }
+
+        text.push_str(&source.source()[token.span.index..token.span.end()]);
 
-        Ok(Token::new(kind, span))
+        for trivia in &token.trailing {
+            text.push_str(trivia.text(source));
+        }
     }
+
+    text
+}
+
+pub fn is_ident_start(c: char) -> bool {
+    c == '_' || unicode_ident::is_xid_start(c)
+}
+
+pub fn is_ident_continue(c: char) -> bool {
+    unicode_ident::is_xid_continue(c)
+}
+
+fn lex_int(
+    digits: &str,
+    radix: Radix,
+    suffix: Option<Int>,
+    span: Span,
+) -> Result<IntLiteral, Error> {
+    let mut value: u128 = 0;
+
+    for c in digits.chars() {
+        let digit = c.to_digit(radix.value()).unwrap();
+
+        let next = value
+            .checked_mul(radix.value() as u128)
+            .and_then(|value| value.checked_add(digit as u128));
+
+        value = match next {
+            Some(value) => value,
+            None => {
+                let err = Error::new("integer literal is too large")
+                    .with_span(span)
+                    .with_note(format!(
+                        "the maximum value of an integer literal is {}",
+                        u128::MAX
+                    ));
+
+                return Err(err);
+            }
+        };
+    }
+
+    if let Some(int) = suffix {
+        let bits = int.size.unwrap_or(64) as u32;
+        let max = match int.signed {
+            true => (1u128 << (bits - 1)) - 1,
+            false => u128::MAX >> (128 - bits),
+        };
+
+        // signed literals may reach the magnitude of the minimum value, so that
+        // negating them produces a valid value, the parser checks the rest
+        let magnitude = match int.signed {
+            true => max + 1,
+            false => max,
+        };
+
+        if value > magnitude {
+            let err = Error::new(format!("literal out of range for '{}'", int))
+                .with_span(span)
+                .with_note(format!("the maximum value of '{}' is {}", int, max));
+
+            return Err(err);
+        }
+    }
+
+    Ok(IntLiteral {
+        value,
+        radix,
+        suffix,
+    })
+}
+
+fn lex_float(float: &str, suffix: Option<Float>, span: Span) -> Result<FloatLiteral, Error> {
+    let value: f64 = float.parse().unwrap();
+
+    let max = match suffix.map(|float| float.size) {
+        Some(16) => 65504.0,
+        Some(32) => f32::MAX as f64,
+        _ => f64::MAX,
+    };
+
+    if !value.is_finite() || value > max {
+        let name = suffix.map_or(String::from("f64"), |float| float.to_string());
+
+        let err = Error::new(format!("literal out of range for '{}'", name))
+            .with_span(span)
+            .with_note(format!("the maximum value of '{}' is {:e}", name, max));
+
+        return Err(err);
+    }
+
+    Ok(FloatLiteral { value, suffix })
 }
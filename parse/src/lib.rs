@@ -1,8 +1,25 @@
+mod attribute;
+mod block;
+mod class;
+mod decl;
+mod enumeration;
 mod error;
 mod expr;
 mod generics;
+pub mod incremental;
 pub mod keyword;
 pub mod lexer;
+mod module;
 pub mod parser;
+mod path;
+mod pattern;
+mod program;
+mod shift;
+mod specialization;
+mod stmt;
 pub mod symbol;
 pub mod token;
+mod ty;
+mod visibility;
+
+pub use program::parse_program;
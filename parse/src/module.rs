@@ -0,0 +1,81 @@
+use athame::{
+    attribute::Attribute,
+    decl::{ModuleDecl, UseDecl},
+    doc::Doc,
+    error::Error,
+    visibility::Visibility,
+};
+
+use crate::{
+    attribute::{docs_and_attributes, inner_docs_and_attributes},
+    decl::decl_or_error,
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+};
+
+/// Parses an inline module after its doc comments, attributes and visibility.
+pub fn module(
+    parser: &mut Parser<'_>,
+    mut docs: Vec<Doc>,
+    mut attributes: Vec<Attribute>,
+    visibility: Visibility,
+) -> Result<ModuleDecl, Error> {
+    let start = parser.span();
+    parser.expect(Keyword::Mod)?;
+
+    let name = parser.parse()?;
+    parser.expect(Symbol::LeftBrace)?;
+
+    let (inner_docs, inner_attributes) = inner_docs_and_attributes(parser)?;
+    docs.extend(inner_docs);
+    attributes.extend(inner_attributes);
+
+    let mut decls = Vec::new();
+
+    while !parser.next_is(Symbol::RightBrace) {
+        if parser.is_empty() {
+            let error = Error::new("unclosed module")
+                .with_span(start)
+                .with_note("expected '}' before the end of file");
+
+            parser.report(error);
+            break;
+        }
+
+        decls.push(decl_or_error(parser));
+    }
+
+    let span = start.with(parser.previous_span());
+
+    Ok(ModuleDecl {
+        visibility,
+        name,
+        decls,
+        docs,
+        attributes,
+        span,
+    })
+}
+
+impl Parse for ModuleDecl {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let visibility = parser.parse()?;
+        module(parser, docs, attributes, visibility)
+    }
+}
+
+impl Parse for UseDecl {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::Use)?;
+
+        let tree = parser.parse()?;
+        parser.expect(Symbol::Semi)?;
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self { tree, span })
+    }
+}
@@ -1,101 +1,445 @@
+use std::collections::VecDeque;
+
 use athame::{
-    error::Error,
+    doc::Doc,
+    error::{Error, Errors},
     ident::Ident,
     sources::{Source, SourceId},
     span::Span,
 };
 
 use crate::{
+    error::Expected,
     lexer::Lexer,
     symbol::Symbol,
-    token::{Token, TokenKind},
+    token::{DocStyle, Token, TokenKind},
 };
 
-pub struct Parser {
+/// A parser pulling tokens from a [`Lexer`] on demand.
+///
+/// Tokens are kept in a ring buffer, which only grows past the lookahead while
+/// [`Parser::try_parse`] might need to backtrack.
+pub struct Parser<'a> {
+    lexer: Lexer<'a>,
+    tokens: VecDeque<Token>,
+    /// The index of the first token in `tokens`.
+    offset: usize,
     index: usize,
-    tokens: Vec<Token>,
+    /// How many braces are open before the current token.
+    depth: usize,
+    backtrack_depth: usize,
+    /// Glued symbols split by [`Parser::next_is`], with the index and the original
+    /// token, so they can be glued back together when backtracking.
+    splits: Vec<(usize, Token)>,
+    last_span: Span,
+    /// What would have been accepted at the furthest token the parser failed at.
+    expected: Vec<Expected>,
+    expected_index: usize,
     source_id: SourceId,
+    errors: Errors,
+    /// The identifiers lexed so far, if checking them is deferred.
+    idents: Vec<(String, Span)>,
+    /// The spans of the invalid tokens [`Parser::unexpected`] failed at, which the
+    /// lexer already reported.
+    invalid_tokens: Vec<Span>,
 }
 
-impl Parser {
-    pub fn new(source: &Source, source_id: SourceId) -> Result<Self, Error> {
-        let mut lexer = Lexer::new(source, source_id);
-        let mut tokens = Vec::new();
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a Source, source_id: SourceId) -> Self {
+        Self {
+            lexer: Lexer::new(source, source_id),
+            tokens: VecDeque::new(),
+            offset: 0,
+            index: 0,
+            depth: 0,
+            backtrack_depth: 0,
+            splits: Vec::new(),
+            last_span: Span::new(0, 0, source_id),
+            expected: Vec::new(),
+            expected_index: 0,
+            source_id,
+            errors: Errors::new(),
+            idents: Vec::new(),
+            invalid_tokens: Vec::new(),
+        }
+    }
+
+    /// Collects the identifiers to be checked after parsing, see [`Parser::take_idents`].
+    pub(crate) fn with_deferred_ident_checks(mut self) -> Self {
+        self.lexer = self.lexer.with_deferred_ident_checks();
+        self
+    }
 
-        while !lexer.is_empty() {
-            tokens.push(lexer.lex()?);
+    /// The identifiers lexed so far in order, if checking them is deferred.
+    pub(crate) fn take_idents(&mut self) -> Vec<(String, Span)> {
+        std::mem::take(&mut self.idents)
+    }
+
+    /// The errors reported so far, including the errors from the lexer.
+    pub fn errors(&self) -> &Errors {
+        &self.errors
+    }
+
+    pub fn take_errors(&mut self) -> Errors {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Reports an error the parser recovered from, unless it is about an invalid token
+    /// the lexer already reported.
+    pub fn report(&mut self, error: Error) {
+        self.expected.clear();
+
+        if !self.invalid_tokens.contains(&error.span()) {
+            self.errors.push(error);
         }
+    }
 
-        Ok(Self {
-            index: 0,
-            tokens,
-            source_id,
-        })
+    /// Reports a warning, which doesn't stop the program from compiling.
+    pub fn warn(&mut self, warning: Error) {
+        self.errors.push_warning(warning);
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.remaining().is_empty()
+    /// Records that `expected` would have been accepted at the current token, unless
+    /// the parser already failed further ahead.
+    fn record_expected(&mut self, expected: Expected) {
+        if self.index > self.expected_index || self.expected.is_empty() {
+            self.expected.clear();
+            self.expected_index = self.index;
+        }
+
+        if self.index == self.expected_index && !self.expected.contains(&expected) {
+            self.expected.push(expected);
+        }
     }
 
-    pub fn remaining(&self) -> &[Token] {
-        &self.tokens[self.index..]
+    /// Records that every one of `expected` would have been accepted at the current
+    /// token, for constructs that may end at any other token.
+    pub(crate) fn record_expected_all(&mut self, expected: &[Expected]) {
+        for expected in expected {
+            self.record_expected(expected.clone());
+        }
     }
 
-    pub fn span(&self) -> Span {
-        if self.tokens.len() == 0 {
-            Span::new(0, 0, self.source_id)
-        } else {
-            let index = self.index.min(self.tokens.len() - 1);
-            self.tokens[index].span
+    /// The error for an unexpected token, after recording that `expected` would have
+    /// been accepted at the current token. The error points at the furthest token the
+    /// parser failed at, including alternatives tried by [`Parser::try_parse`], and
+    /// lists everything that was expected there.
+    pub fn unexpected(&mut self, expected: &[Expected]) -> Error {
+        self.record_expected_all(expected);
+
+        let is_furthest = !self.expected.is_empty() && self.expected_index >= self.index;
+        let (index, expected) = match is_furthest {
+            true => (self.expected_index, self.expected.clone()),
+            false => (self.index, Vec::new()),
+        };
+
+        let last_span = self.last_span;
+        let (found, span) = match self.get(index) {
+            Some(token) if token.kind == TokenKind::Error => {
+                let span = token.span;
+                self.invalid_tokens.push(span);
+
+                (Some(format!("'{}'", TokenKind::Error)), span)
+            }
+            Some(token) => (Some(format!("'{}'", token.kind)), token.span),
+            None => (None, last_span),
+        };
+
+        let expected: Vec<_> = expected.iter().map(ToString::to_string).collect();
+
+        let message = match (expected.as_slice(), found) {
+            ([], Some(found)) => format!("unexpected token {}", found),
+            ([], None) => String::from("unexpected end of file"),
+            ([expected], found) => format!(
+                "expected {}, found {}",
+                expected,
+                found.as_deref().unwrap_or("end of file")
+            ),
+            (expected, found) => format!(
+                "expected one of {}, found {}",
+                expected.join(", "),
+                found.as_deref().unwrap_or("end of file")
+            ),
+        };
+
+        Error::new(message).with_span(span)
+    }
+
+    /// Where the parser is, used with [`Parser::recover`].
+    pub fn position(&self) -> Position {
+        Position {
+            index: self.index,
+            depth: self.depth,
         }
     }
 
-    pub fn try_next(&mut self) -> Option<&Token> {
-        let token = self.tokens.get(self.index)?;
+    /// Records the state of the parser before the current token, see [`Checkpoint`].
+    pub(crate) fn checkpoint(&mut self) -> Checkpoint {
+        self.fill(self.index);
+
+        let current = self.index - self.offset;
+        let previous = current
+            .checked_sub(1)
+            .map(|index| self.tokens[index].clone());
+
+        // only what was expected at or after the current token can still be reported
+        let expected = match !self.expected.is_empty() && self.expected_index >= self.index {
+            true => Some((self.expected_index - self.index, self.expected.clone())),
+            false => None,
+        };
+
+        Checkpoint {
+            previous,
+            tokens: self.tokens.range(current..).cloned().collect(),
+            lexed: self.lexer.index(),
+            depth: self.depth,
+            expected,
+            errors: self.errors.len(),
+            warnings: self.errors.warnings().len(),
+            idents: self.idents.len(),
+        }
+    }
+
+    /// Creates a parser for `source` in the state recorded by `checkpoint`, with the
+    /// `errors` reported and the `idents` lexed before it, deferring identifier
+    /// checks. The text of `source` before [`Checkpoint::lexed`] must not have
+    /// changed since the checkpoint.
+    pub(crate) fn resume(
+        source: &'a Source,
+        source_id: SourceId,
+        checkpoint: &Checkpoint,
+        errors: Errors,
+        idents: Vec<(String, Span)>,
+    ) -> Self {
+        let mut parser = Self::new(source, source_id).with_deferred_ident_checks();
+        parser.lexer.seek(checkpoint.lexed);
+
+        let previous = checkpoint.previous.iter();
+        parser.tokens = previous.chain(&checkpoint.tokens).cloned().collect();
+        parser.index = checkpoint.previous.iter().count();
+        parser.depth = checkpoint.depth;
+        parser.errors = errors;
+        parser.idents = idents;
+
+        if let Some(last) = parser.tokens.back() {
+            parser.last_span = last.span;
+        }
+
+        if let Some((offset, ref expected)) = checkpoint.expected {
+            parser.expected = expected.clone();
+            parser.expected_index = parser.index + offset;
+        }
+
+        parser
+    }
+
+    pub fn source_id(&self) -> SourceId {
+        self.source_id
+    }
+
+    /// Makes sure the token at `index` is buffered, unless the lexer is exhausted.
+    fn fill(&mut self, index: usize) -> bool {
+        while self.offset + self.tokens.len() <= index {
+            let Some(token) = self.lexer.next() else {
+                return false;
+            };
+
+            self.errors.extend(self.lexer.take_errors());
+            self.idents.extend(self.lexer.take_idents());
+
+            if token.kind == TokenKind::Eof {
+                return false;
+            }
+
+            self.last_span = token.span;
+            self.tokens.push_back(token);
+        }
+
+        true
+    }
+
+    fn get(&mut self, index: usize) -> Option<&Token> {
+        if !self.fill(index) {
+            return None;
+        }
+
+        self.tokens.get(index - self.offset)
+    }
+
+    /// Advances past the current token, discarding tokens that can no longer be
+    /// backtracked to, except for the previous token.
+    pub(crate) fn advance(&mut self) {
+        if let Some(token) = self.tokens.get(self.index - self.offset) {
+            match token.kind {
+                TokenKind::Symbol(Symbol::LeftBrace) => self.depth += 1,
+                TokenKind::Symbol(Symbol::RightBrace) => {
+                    self.depth = self.depth.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+
         self.index += 1;
-        Some(token)
+
+        if self.backtrack_depth == 0 {
+            self.splits.clear();
+
+            while self.offset + 1 < self.index && !self.tokens.is_empty() {
+                self.tokens.pop_front();
+                self.offset += 1;
+            }
+        }
     }
 
-    pub fn try_peek(&self) -> Option<&Token> {
-        self.remaining().first()
+    pub fn is_empty(&mut self) -> bool {
+        self.try_peek().is_none()
     }
 
-    pub fn next(&mut self) -> Result<&Token, Error> {
-        let span = self.span();
+    pub fn span(&mut self) -> Span {
+        match self.try_peek() {
+            Some(token) => token.span,
+            None => self.last_span,
+        }
+    }
 
-        if let Some(token) = self.try_next() {
-            Ok(token)
-        } else {
-            let token = Error::new("unexpected end of file").with_span(span);
+    /// The span of the last consumed token.
+    pub fn previous_span(&mut self) -> Span {
+        match self.index.checked_sub(1) {
+            Some(index) => {
+                let last_span = self.last_span;
+                self.get(index).map_or(last_span, |token| token.span)
+            }
+            None => Span::new(0, 0, self.source_id),
+        }
+    }
 
-            Err(token)
+    pub fn try_next(&mut self) -> Option<&Token> {
+        if !self.fill(self.index) {
+            return None;
         }
+
+        self.advance();
+        self.tokens.get(self.index - 1 - self.offset)
     }
 
-    pub fn peek(&self) -> Result<&Token, Error> {
-        if let Some(token) = self.try_peek() {
-            Ok(token)
-        } else {
-            let token = Error::new("unexpected end of file").with_span(self.span());
+    pub fn try_peek(&mut self) -> Option<&Token> {
+        self.get(self.index)
+    }
+
+    /// Peeks the token `n` tokens ahead of the current token.
+    pub fn try_peek_nth(&mut self, n: usize) -> Option<&Token> {
+        self.get(self.index + n)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<&Token, Error> {
+        if self.is_empty() {
+            return Err(self.unexpected(&[]));
+        }
+
+        Ok(self.try_next().unwrap())
+    }
 
-            Err(token)
+    pub fn peek(&mut self) -> Result<&Token, Error> {
+        if self.is_empty() {
+            return Err(self.unexpected(&[]));
         }
+
+        Ok(self.try_peek().unwrap())
     }
 
+    /// Consumes the current token if it is `value`, otherwise `value` is recorded as
+    /// expected here, see [`Parser::unexpected`].
     pub fn next_is<T>(&mut self, value: T) -> bool
     where
         TokenKind: PartialEq<T>,
+        T: Into<Expected>,
     {
         let Some(token) = self.try_peek() else {
+            self.record_expected(value.into());
             return false;
         };
 
         if token.kind == value {
-            self.index += 1;
+            self.advance();
 
-            true
-        } else {
-            false
+            return true;
+        }
+
+        if let TokenKind::Symbol(symbol) = token.kind {
+            if let Some((first, rest)) = symbol.split() {
+                if TokenKind::Symbol(first) == value {
+                    self.split(first, rest);
+                    self.advance();
+
+                    return true;
+                }
+            }
+        }
+
+        self.record_expected(value.into());
+
+        false
+    }
+
+    /// Splits the current token into `first` and `rest`.
+    fn split(&mut self, first: Symbol, rest: Symbol) {
+        let position = self.index - self.offset;
+        let token = self.tokens[position].clone();
+
+        let length = first.as_str().len();
+        let first_span = Span::new(token.span.index, length, self.source_id);
+        let rest_span = Span::new(
+            token.span.index + length,
+            token.span.length - length,
+            self.source_id,
+        );
+
+        self.tokens[position] = Token::new(TokenKind::Symbol(first), first_span);
+        self.tokens
+            .insert(position + 1, Token::new(TokenKind::Symbol(rest), rest_span));
+
+        self.splits.push((self.index, token));
+    }
+
+    /// Moves back to `index`, gluing split symbols back together.
+    fn backtrack(&mut self, index: usize) {
+        while let Some(&(split, _)) = self.splits.last() {
+            if split < index {
+                break;
+            }
+
+            let (_, token) = self.splits.pop().unwrap();
+            let position = split - self.offset;
+
+            self.tokens.remove(position + 1);
+            self.tokens[position] = token;
+        }
+
+        self.index = index;
+    }
+
+    /// Skips the rest of a construct that failed to parse after starting at `start`,
+    /// stopping before a token matching `is_sync` or a `}`, once outside of the
+    /// braces opened by the construct. At least one token is skipped if the construct
+    /// failed without consuming anything.
+    pub fn recover(&mut self, start: Position, is_sync: impl Fn(&TokenKind) -> bool) {
+        if self.index == start.index {
+            self.try_next();
+        }
+
+        loop {
+            let is_outside = self.depth <= start.depth;
+
+            let Some(token) = self.try_peek() else {
+                break;
+            };
+
+            if is_outside && (token.kind == Symbol::RightBrace || is_sync(&token.kind)) {
+                break;
+            }
+
+            self.advance();
         }
     }
 
@@ -105,11 +449,17 @@ impl Parser {
 
     pub fn try_parse<T: Parse>(&mut self) -> Option<T> {
         let index = self.index;
+        let depth = self.depth;
 
-        match T::parse(self) {
+        self.backtrack_depth += 1;
+        let result = T::parse(self);
+        self.backtrack_depth -= 1;
+
+        match result {
             Ok(value) => Some(value),
             Err(_) => {
-                self.index = index;
+                self.backtrack(index);
+                self.depth = depth;
                 None
             }
         }
@@ -118,7 +468,7 @@ impl Parser {
     pub fn parse_spanned<T: Parse>(&mut self) -> Result<(T, Span), Error> {
         let start = self.span();
         let value = T::parse(self)?;
-        let end = self.span();
+        let end = self.previous_span();
 
         Ok((value, start.with(end)))
     }
@@ -126,7 +476,7 @@ impl Parser {
     pub fn try_parse_spanned<T: Parse>(&mut self) -> Option<(T, Span)> {
         let start = self.span();
         let value = self.try_parse::<T>()?;
-        let end = self.span();
+        let end = self.previous_span();
 
         Some((value, start.with(end)))
     }
@@ -134,26 +484,69 @@ impl Parser {
     pub fn expect<T>(&mut self, value: T) -> Result<(), Error>
     where
         TokenKind: PartialEq<T>,
-        T: std::fmt::Display,
+        T: Into<Expected>,
     {
-        if self.next()?.kind == value {
+        if self.next_is(value) {
             Ok(())
         } else {
-            Err(Error::new("unexpected token"))
+            Err(self.unexpected(&[]))
+        }
+    }
+
+    fn parse_doc(&mut self, style: DocStyle) -> Option<Doc> {
+        let token = self.try_peek()?;
+
+        let TokenKind::DocComment(ref doc) = token.kind else {
+            return None;
+        };
+
+        if doc.style != style {
+            return None;
+        }
+
+        let doc = Doc {
+            text: doc.text.clone(),
+            span: token.span,
+        };
+
+        self.advance();
+
+        Some(doc)
+    }
+
+    /// Parses `///` doc comments documenting the following item.
+    pub fn parse_docs(&mut self) -> Vec<Doc> {
+        let mut docs = Vec::new();
+
+        while let Some(doc) = self.parse_doc(DocStyle::Outer) {
+            docs.push(doc);
+        }
+
+        docs
+    }
+
+    /// Parses `//!` doc comments documenting the enclosing module.
+    pub fn parse_inner_docs(&mut self) -> Vec<Doc> {
+        let mut docs = Vec::new();
+
+        while let Some(doc) = self.parse_doc(DocStyle::Inner) {
+            docs.push(doc);
         }
+
+        docs
     }
 
-    pub fn parse_list<T: Parse>(&mut self, terminator: &TokenKind) -> Result<Vec<T>, Error> {
+    pub fn parse_list<T: Parse>(&mut self, terminator: Symbol) -> Result<Vec<T>, Error> {
         let mut list = Vec::new();
 
         loop {
-            if self.next_is(terminator.clone()) {
+            if self.next_is(terminator) {
                 break;
             }
 
             list.push(self.parse()?);
 
-            if self.next_is(terminator.clone()) {
+            if self.next_is(terminator) {
                 break;
             }
 
@@ -164,18 +557,51 @@ impl Parser {
     }
 }
 
+/// A position of the [`Parser`], see [`Parser::position`].
+#[derive(Clone, Copy, Debug)]
+pub struct Position {
+    index: usize,
+    depth: usize,
+}
+
+/// The state of the [`Parser`] before a top level declaration, everything besides
+/// the rest of the source that decides how the rest of the source is parsed.
+#[derive(Clone, Debug)]
+pub(crate) struct Checkpoint {
+    /// The token before the current token, if any.
+    pub previous: Option<Token>,
+    /// The current token, followed by the tokens the parser already looked ahead at.
+    pub tokens: Vec<Token>,
+    /// How far into the source the lexer is.
+    pub lexed: usize,
+    pub depth: usize,
+    /// What was expected at the furthest token the parser failed at, relative to the
+    /// current token.
+    pub expected: Option<(usize, Vec<Expected>)>,
+    /// The number of errors and warnings reported before the checkpoint.
+    pub errors: usize,
+    pub warnings: usize,
+    /// The number of identifiers lexed before the checkpoint, if checking them is
+    /// deferred.
+    pub idents: usize,
+}
+
 pub trait Parse: Sized {
-    fn parse(parser: &mut Parser) -> Result<Self, Error>;
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error>;
 }
 
 impl Parse for Ident {
-    fn parse(parser: &mut Parser) -> Result<Self, Error> {
-        let token = parser.next()?;
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let token = parser.peek()?;
 
+        // the token is only consumed if it is an identifier, so recovery can resume at it
         if let TokenKind::Ident(ref ident) = token.kind {
-            Ok(Ident::new(ident.as_str(), token.span))
+            let ident = Ident::new(ident.as_str(), token.span);
+            parser.advance();
+
+            Ok(ident)
         } else {
-            Err(Error::new("expected identifier").with_span(token.span))
+            Err(parser.unexpected(&[Expected::Ident]))
         }
     }
 }
@@ -0,0 +1,201 @@
+use athame::{
+    decl::UseTree,
+    error::Error,
+    ident::Ident,
+    path::{CrateSegment, IdentSegment, Path, PathSegment, SelfSegment, SuperSegment},
+    span::Span,
+    specialization::Spec,
+};
+
+use crate::{
+    keyword::{ContextualKeyword, Keyword},
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::TokenKind,
+};
+
+/// Where a path is parsed, which decides how generic arguments are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PathStyle {
+    /// `a::List<i32>`.
+    Type,
+    /// `a::make::<i32>`, since `<` could be a comparison.
+    Expr,
+    /// `a::b`, without generic arguments.
+    Use,
+}
+
+fn ident_segment(parser: &mut Parser<'_>, style: PathStyle) -> Result<IdentSegment, Error> {
+    let name = parser.parse::<Ident>()?;
+
+    let has_spec = match style {
+        PathStyle::Use => false,
+        PathStyle::Type => matches!(parser.try_peek(), Some(token) if token.kind == Symbol::Lt),
+        PathStyle::Expr => {
+            let is_separator = matches!(
+                parser.try_peek(),
+                Some(token) if token.kind == Symbol::ColonColon
+            );
+
+            is_separator
+                && matches!(parser.try_peek_nth(1), Some(token) if token.kind == Symbol::Lt)
+        }
+    };
+
+    let spec = match has_spec {
+        true => {
+            if style == PathStyle::Expr {
+                parser.expect(Symbol::ColonColon)?;
+            }
+
+            parser.parse::<Spec>()?
+        }
+        false => Spec::empty(),
+    };
+
+    let span = name.span().with(parser.previous_span());
+
+    Ok(IdentSegment { name, spec, span })
+}
+
+/// Parses the next segment of a path, `self` and `crate` may only start a relative
+/// path, and `super` may only follow `super` or start a relative path.
+fn segment(
+    parser: &mut Parser<'_>,
+    style: PathStyle,
+    is_absolute: bool,
+    previous: &[PathSegment],
+) -> Result<PathSegment, Error> {
+    let is_start = !is_absolute && previous.is_empty();
+    let is_super_allowed = !is_absolute
+        && previous
+            .iter()
+            .all(|segment| matches!(segment, PathSegment::SuperSegment(_)));
+
+    if is_start && parser.next_is(Keyword::Self_) {
+        let span = parser.previous_span();
+        return Ok(PathSegment::SelfSegment(SelfSegment { span }));
+    }
+
+    // `crate` is only a keyword at the start of a longer path, anywhere else it's
+    // an identifier
+    let is_crate_allowed = is_start
+        && matches!(parser.try_peek_nth(1), Some(token) if token.kind == Symbol::ColonColon);
+
+    if is_crate_allowed && parser.next_is(ContextualKeyword::Crate) {
+        let span = parser.previous_span();
+        return Ok(PathSegment::CrateSegment(CrateSegment { span }));
+    }
+
+    if is_super_allowed && parser.next_is(Keyword::Super) {
+        let span = parser.previous_span();
+        return Ok(PathSegment::SuperSegment(SuperSegment { span }));
+    }
+
+    Ok(PathSegment::IdentSegment(ident_segment(parser, style)?))
+}
+
+fn path(parser: &mut Parser<'_>, style: PathStyle) -> Result<Path, Error> {
+    let start = parser.span();
+    let is_absolute = parser.next_is(Symbol::ColonColon);
+
+    let mut segments = Vec::new();
+
+    loop {
+        let segment = segment(parser, style, is_absolute, &segments)?;
+        segments.push(segment);
+
+        if !is_separator(parser) {
+            break;
+        }
+
+        parser.expect(Symbol::ColonColon)?;
+    }
+
+    let span = start.with(parser.previous_span());
+
+    Ok(Path {
+        is_absolute,
+        segments,
+        span,
+    })
+}
+
+/// Whether the next token is a `::` followed by another segment.
+fn is_separator(parser: &mut Parser<'_>) -> bool {
+    let is_separator = matches!(
+        parser.try_peek(),
+        Some(token) if token.kind == Symbol::ColonColon
+    );
+
+    is_separator
+        && matches!(
+            parser.try_peek_nth(1),
+            Some(token) if matches!(
+                token.kind,
+                TokenKind::Ident(_) | TokenKind::Keyword(Keyword::Self_ | Keyword::Super)
+            )
+        )
+}
+
+/// Parses a path in a type, where generic arguments follow segments directly.
+pub fn type_path(parser: &mut Parser<'_>) -> Result<Path, Error> {
+    path(parser, PathStyle::Type)
+}
+
+// paths parsed on their own are expression paths
+impl Parse for Path {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        path(parser, PathStyle::Expr)
+    }
+}
+
+/// Whether the next tokens end the prefix of a glob or a group in a use tree.
+fn is_use_prefix_end(parser: &mut Parser<'_>, n: usize) -> bool {
+    matches!(
+        parser.try_peek_nth(n),
+        Some(token) if token.kind == Symbol::LeftBrace || token.kind == Symbol::Star
+    )
+}
+
+impl Parse for UseTree {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+
+        let is_prefix_empty = is_use_prefix_end(parser, 0)
+            || matches!(parser.try_peek(), Some(token) if token.kind == Symbol::ColonColon)
+                && is_use_prefix_end(parser, 1);
+
+        let prefix = if is_prefix_empty {
+            let is_absolute = parser.next_is(Symbol::ColonColon);
+
+            Path {
+                is_absolute,
+                segments: Vec::new(),
+                span: Span::new(start.index, 0, start.source),
+            }
+        } else {
+            let path = path(parser, PathStyle::Use)?;
+
+            if !parser.next_is(Symbol::ColonColon) {
+                let alias = match parser.next_is(Keyword::As) {
+                    true => Some(parser.parse()?),
+                    false => None,
+                };
+
+                return Ok(Self::Path { path, alias });
+            }
+
+            path
+        };
+
+        if parser.next_is(Symbol::Star) {
+            return Ok(Self::Glob { prefix });
+        }
+
+        parser.expect(Symbol::LeftBrace)?;
+        let trees = parser.parse_list(Symbol::RightBrace)?;
+
+        Ok(Self::Group { prefix, trees })
+    }
+}
@@ -0,0 +1,157 @@
+use athame::{
+    error::Error,
+    expr::LiteralExpr,
+    ident::Ident,
+    path::{Path, PathSegment},
+    pattern::{FieldPattern, Pattern, PatternKind},
+};
+
+use crate::{
+    error::Expected,
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::TokenKind,
+};
+
+/// The binding a path is, if it's a single plain identifier.
+fn binding(path: &Path) -> Option<&Ident> {
+    match path.segments.as_slice() {
+        [PathSegment::IdentSegment(segment)] if !path.is_absolute && segment.spec.is_empty() => {
+            Some(&segment.name)
+        }
+        _ => None,
+    }
+}
+
+impl Parse for FieldPattern {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let name = parser.parse::<Ident>()?;
+
+        // `name` is short for `name: name`
+        let pattern = match parser.next_is(Symbol::Colon) {
+            true => parser.parse()?,
+            false => Pattern {
+                kind: PatternKind::Binding(name.clone()),
+                span: name.span(),
+            },
+        };
+
+        let span = name.span().with(parser.previous_span());
+
+        Ok(Self {
+            name,
+            pattern,
+            span,
+        })
+    }
+}
+
+/// Parses the fields of a struct variant pattern after the `{`, returning whether
+/// they end with `..`.
+fn fields(parser: &mut Parser<'_>) -> Result<(Vec<FieldPattern>, bool), Error> {
+    let mut fields = Vec::new();
+
+    loop {
+        if parser.next_is(Symbol::RightBrace) {
+            return Ok((fields, false));
+        }
+
+        if parser.next_is(Symbol::DotDot) {
+            parser.expect(Symbol::RightBrace)?;
+            return Ok((fields, true));
+        }
+
+        fields.push(parser.parse()?);
+
+        if !parser.next_is(Symbol::Comma) {
+            parser.expect(Symbol::RightBrace)?;
+            return Ok((fields, false));
+        }
+    }
+}
+
+/// Parses a pattern without `|` alternatives.
+fn single(parser: &mut Parser<'_>) -> Result<Pattern, Error> {
+    let start = parser.span();
+    let token = parser.peek()?;
+
+    let kind = match token.kind {
+        TokenKind::Ident(ref name) if name == "_" => {
+            parser.next()?;
+            PatternKind::Wildcard
+        }
+        TokenKind::Int(_)
+        | TokenKind::Float(_)
+        | TokenKind::Byte(_)
+        | TokenKind::Char(_)
+        | TokenKind::String(_)
+        | TokenKind::ByteString(_)
+        | TokenKind::Keyword(Keyword::True | Keyword::False) => {
+            PatternKind::Literal(parser.parse::<LiteralExpr>()?.kind)
+        }
+        TokenKind::Symbol(Symbol::LeftParen) => {
+            parser.next()?;
+            let pattern = parser.parse::<Pattern>()?;
+            parser.expect(Symbol::RightParen)?;
+
+            pattern.kind
+        }
+        TokenKind::Ident(_)
+        | TokenKind::Symbol(Symbol::ColonColon)
+        | TokenKind::Keyword(Keyword::Self_ | Keyword::Super) => {
+            let path = parser.parse::<Path>()?;
+
+            if parser.next_is(Symbol::LeftParen) {
+                let fields = parser.parse_list(Symbol::RightParen)?;
+                PatternKind::Tuple { path, fields }
+            } else if parser.next_is(Symbol::LeftBrace) {
+                let (fields, has_rest) = fields(parser)?;
+
+                PatternKind::Struct {
+                    path,
+                    fields,
+                    has_rest,
+                }
+            } else if let Some(name) = binding(&path) {
+                PatternKind::Binding(name.clone())
+            } else {
+                PatternKind::Path(path)
+            }
+        }
+        _ => {
+            return Err(parser.unexpected(&[
+                Expected::Ident,
+                Expected::Literal,
+                Expected::Symbol(Symbol::LeftParen),
+            ]))
+        }
+    };
+
+    let span = start.with(parser.previous_span());
+
+    Ok(Pattern { kind, span })
+}
+
+impl Parse for Pattern {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let first = single(parser)?;
+
+        if !matches!(parser.try_peek(), Some(token) if token.kind == Symbol::Pipe) {
+            return Ok(first);
+        }
+
+        let mut patterns = vec![first];
+
+        while parser.next_is(Symbol::Pipe) {
+            patterns.push(single(parser)?);
+        }
+
+        let span = patterns[0].span.with(parser.previous_span());
+
+        Ok(Pattern {
+            kind: PatternKind::Or(patterns),
+            span,
+        })
+    }
+}
@@ -0,0 +1,82 @@
+use athame::{
+    decl::Decl,
+    error::{Error, Errors},
+    program::Program,
+    sources::{SourceId, Sources},
+};
+
+use crate::{
+    attribute::inner_docs_and_attributes,
+    decl::decl_or_error,
+    parser::{Checkpoint, Parse, Parser},
+};
+
+/// Parses the doc comments and attributes of the file, with no declarations yet.
+pub(crate) fn program_header(parser: &mut Parser<'_>) -> Program {
+    let (docs, attributes) = match inner_docs_and_attributes(parser) {
+        Ok(inner) => inner,
+        Err(error) => {
+            parser.report(error);
+            (Vec::new(), Vec::new())
+        }
+    };
+
+    Program {
+        decls: Vec::new(),
+        docs,
+        attributes,
+    }
+}
+
+/// Parses declarations into `decls` until the end of file, recovering from syntax
+/// errors. The state of the parser before each declaration is pushed to
+/// `checkpoints`, parsing stops before the declaration if `stop` returns true for it.
+pub(crate) fn program_decls(
+    parser: &mut Parser<'_>,
+    decls: &mut Vec<Decl>,
+    checkpoints: &mut Vec<Checkpoint>,
+    mut stop: impl FnMut(&Checkpoint) -> bool,
+) {
+    while !parser.is_empty() {
+        let checkpoint = parser.checkpoint();
+
+        if stop(&checkpoint) {
+            break;
+        }
+
+        checkpoints.push(checkpoint);
+        decls.push(decl_or_error(parser));
+    }
+}
+
+/// Parses the declarations until the end of file, recovering from syntax errors.
+fn program(parser: &mut Parser<'_>) -> Program {
+    let mut program = program_header(parser);
+
+    while !parser.is_empty() {
+        program.decls.push(decl_or_error(parser));
+    }
+
+    program
+}
+
+impl Parse for Program {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        Ok(program(parser))
+    }
+}
+
+/// Parses the source `id` into a [`Program`], with the errors and warnings reported
+/// while lexing and parsing it. Declarations and statements that fail to parse are
+/// replaced by [`Decl::Error`](athame::decl::Decl::Error) and
+/// [`Expr::Error`](athame::expr::Expr::Error) placeholders.
+///
+/// Panics if `id` is not in `sources`.
+pub fn parse_program(sources: &Sources, id: SourceId) -> (Program, Errors) {
+    let source = sources.get(id).expect("source not found");
+    let mut parser = Parser::new(source, id);
+
+    let program = program(&mut parser);
+
+    (program, parser.take_errors())
+}
@@ -0,0 +1,637 @@
+use athame::{
+    attribute::{Attribute, AttributeArg, AttributeValue},
+    block::Block,
+    class::Class,
+    decl::{ConstDecl, Decl, FunctionDecl, ModuleDecl, StaticDecl, UseDecl, UseTree},
+    doc::Doc,
+    enumeration::{Enum, Variant, VariantKind},
+    expr::{
+        AssignExpr, BinaryExpr, BreakExpr, CallExpr, ContinueExpr, Expr, FieldExpr, ForExpr,
+        IfExpr, IndexExpr, LiteralExpr, LoopExpr, MatchArm, MatchExpr, MethodCallExpr, ParenExpr,
+        UnaryExpr, WhileExpr,
+    },
+    field::Field,
+    function::{Argument, Signature},
+    generics::{Generic, Generics},
+    ident::Ident,
+    method::{Method, SelfArgument},
+    path::{Path, PathSegment},
+    pattern::{FieldPattern, Pattern, PatternKind},
+    span::Span,
+    specialization::{Spec, SpecGenerics},
+    stmt::{LetStmt, ReturnStmt, Stmt, StmtKind},
+    ty::Type,
+    visibility::Visibility,
+};
+
+/// Moves every span in a syntax tree, used to reuse declarations after an edit.
+///
+/// Fields are destructured, so adding a field without shifting it fails to compile.
+pub(crate) trait Shift {
+    fn shift(&mut self, delta: isize);
+}
+
+impl Shift for Span {
+    fn shift(&mut self, delta: isize) {
+        *self = self.shifted(delta);
+    }
+}
+
+impl<T: Shift> Shift for Box<T> {
+    fn shift(&mut self, delta: isize) {
+        T::shift(self, delta);
+    }
+}
+
+impl<T: Shift> Shift for Option<T> {
+    fn shift(&mut self, delta: isize) {
+        if let Some(value) = self {
+            value.shift(delta);
+        }
+    }
+}
+
+impl<T: Shift> Shift for Vec<T> {
+    fn shift(&mut self, delta: isize) {
+        for value in self {
+            value.shift(delta);
+        }
+    }
+}
+
+impl Shift for Ident {
+    fn shift(&mut self, delta: isize) {
+        self.set_span(self.span().shifted(delta));
+    }
+}
+
+impl Shift for Doc {
+    fn shift(&mut self, delta: isize) {
+        self.span.shift(delta);
+    }
+}
+
+impl Shift for Visibility {
+    fn shift(&mut self, delta: isize) {
+        if let Self::Public(span) = self {
+            span.shift(delta);
+        }
+    }
+}
+
+impl Shift for AttributeValue {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Ident(ident) => ident.shift(delta),
+            Self::Literal(literal) => literal.shift(delta),
+        }
+    }
+}
+
+impl Shift for AttributeArg {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Value(value) => value.shift(delta),
+            Self::KeyValue { key, value, span } => {
+                key.shift(delta);
+                value.shift(delta);
+                span.shift(delta);
+            }
+        }
+    }
+}
+
+impl Shift for Attribute {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            style: _,
+            name,
+            args,
+            span,
+        } = self;
+
+        name.shift(delta);
+        args.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Generic {
+    fn shift(&mut self, delta: isize) {
+        self.name.shift(delta);
+    }
+}
+
+impl Shift for Generics {
+    fn shift(&mut self, delta: isize) {
+        self.params.shift(delta);
+    }
+}
+
+impl Shift for Spec {
+    fn shift(&mut self, delta: isize) {
+        self.generics.shift(delta);
+    }
+}
+
+impl Shift for SpecGenerics {
+    fn shift(&mut self, delta: isize) {
+        for generic in &mut self.generics {
+            generic.generic.shift(delta);
+            generic.ty.shift(delta);
+        }
+    }
+}
+
+impl Shift for PathSegment {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::IdentSegment(segment) => {
+                segment.name.shift(delta);
+                segment.spec.shift(delta);
+                segment.span.shift(delta);
+            }
+            Self::SelfSegment(segment) => segment.span.shift(delta),
+            Self::SuperSegment(segment) => segment.span.shift(delta),
+            Self::CrateSegment(segment) => segment.span.shift(delta),
+        }
+    }
+}
+
+impl Shift for Path {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            is_absolute: _,
+            segments,
+            span,
+        } = self;
+
+        segments.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Signature {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            generics,
+            arguments,
+            return_ty,
+        } = self;
+
+        generics.shift(delta);
+        arguments.shift(delta);
+        return_ty.shift(delta);
+    }
+}
+
+impl Shift for Type {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Void | Self::Bool | Self::Int(_) | Self::Float(_) => {}
+            Self::Pointer(ty) | Self::Array(ty, _) => ty.shift(delta),
+            Self::Function(signature) => signature.shift(delta),
+            Self::Class(class) => {
+                class.path.shift(delta);
+                class.spec.shift(delta);
+            }
+            Self::Unresolved(path) => path.shift(delta),
+        }
+    }
+}
+
+impl Shift for FieldPattern {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            name,
+            pattern,
+            span,
+        } = self;
+
+        name.shift(delta);
+        pattern.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Pattern {
+    fn shift(&mut self, delta: isize) {
+        match &mut self.kind {
+            PatternKind::Wildcard | PatternKind::Literal(_) => {}
+            PatternKind::Binding(name) => name.shift(delta),
+            PatternKind::Path(path) => path.shift(delta),
+            PatternKind::Tuple { path, fields } => {
+                path.shift(delta);
+                fields.shift(delta);
+            }
+            PatternKind::Struct {
+                path,
+                fields,
+                has_rest: _,
+            } => {
+                path.shift(delta);
+                fields.shift(delta);
+            }
+            PatternKind::Or(patterns) => patterns.shift(delta),
+        }
+
+        self.span.shift(delta);
+    }
+}
+
+impl Shift for LiteralExpr {
+    fn shift(&mut self, delta: isize) {
+        self.span.shift(delta);
+    }
+}
+
+impl Shift for MatchArm {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            pattern,
+            body,
+            span,
+        } = self;
+
+        pattern.shift(delta);
+        body.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Expr {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Literal(expr) => expr.shift(delta),
+            Self::Paren(ParenExpr { expr, span }) => {
+                expr.shift(delta);
+                span.shift(delta);
+            }
+            Self::Path(path) => path.shift(delta),
+            Self::Field(FieldExpr { class, field, span }) => {
+                class.shift(delta);
+                field.shift(delta);
+                span.shift(delta);
+            }
+            Self::Call(CallExpr {
+                callee,
+                arguments,
+                span,
+            }) => {
+                callee.shift(delta);
+                arguments.shift(delta);
+                span.shift(delta);
+            }
+            Self::MethodCall(MethodCallExpr {
+                receiver,
+                method,
+                spec,
+                arguments,
+                span,
+            }) => {
+                receiver.shift(delta);
+                method.shift(delta);
+                spec.shift(delta);
+                arguments.shift(delta);
+                span.shift(delta);
+            }
+            Self::Index(IndexExpr { expr, index, span }) => {
+                expr.shift(delta);
+                index.shift(delta);
+                span.shift(delta);
+            }
+            Self::Unary(UnaryExpr { op: _, expr, span }) => {
+                expr.shift(delta);
+                span.shift(delta);
+            }
+            Self::Binary(BinaryExpr {
+                lhs,
+                op: _,
+                rhs,
+                span,
+            })
+            | Self::Assign(AssignExpr { lhs, rhs, span }) => {
+                lhs.shift(delta);
+                rhs.shift(delta);
+                span.shift(delta);
+            }
+            Self::Block(block) => block.shift(delta),
+            Self::If(IfExpr {
+                condition,
+                then_block,
+                else_branch,
+                span,
+            }) => {
+                condition.shift(delta);
+                then_block.shift(delta);
+                else_branch.shift(delta);
+                span.shift(delta);
+            }
+            Self::Loop(LoopExpr { label, body, span }) => {
+                label.shift(delta);
+                body.shift(delta);
+                span.shift(delta);
+            }
+            Self::While(WhileExpr {
+                label,
+                condition,
+                body,
+                span,
+            }) => {
+                label.shift(delta);
+                condition.shift(delta);
+                body.shift(delta);
+                span.shift(delta);
+            }
+            Self::For(ForExpr {
+                label,
+                binding,
+                iterator,
+                body,
+                span,
+            }) => {
+                label.shift(delta);
+                binding.shift(delta);
+                iterator.shift(delta);
+                body.shift(delta);
+                span.shift(delta);
+            }
+            Self::Break(BreakExpr { label, value, span }) => {
+                label.shift(delta);
+                value.shift(delta);
+                span.shift(delta);
+            }
+            Self::Continue(ContinueExpr { label, span }) => {
+                label.shift(delta);
+                span.shift(delta);
+            }
+            Self::Match(MatchExpr { expr, arms, span }) => {
+                expr.shift(delta);
+                arms.shift(delta);
+                span.shift(delta);
+            }
+            Self::Error(span) => span.shift(delta),
+        }
+    }
+}
+
+impl Shift for Stmt {
+    fn shift(&mut self, delta: isize) {
+        match &mut self.kind {
+            StmtKind::Expr(expr) => expr.shift(delta),
+            StmtKind::Let(LetStmt {
+                name,
+                ty,
+                expr,
+                span,
+            }) => {
+                name.shift(delta);
+                ty.shift(delta);
+                expr.shift(delta);
+                span.shift(delta);
+            }
+            StmtKind::Return(ReturnStmt { expr, span }) => {
+                expr.shift(delta);
+                span.shift(delta);
+            }
+        }
+
+        self.attributes.shift(delta);
+        self.span.shift(delta);
+    }
+}
+
+impl Shift for Block {
+    fn shift(&mut self, delta: isize) {
+        let Self { stmts, expr, span } = self;
+
+        stmts.shift(delta);
+        expr.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Argument {
+    fn shift(&mut self, delta: isize) {
+        self.name.shift(delta);
+        self.ty.shift(delta);
+    }
+}
+
+impl Shift for Field {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            visibility,
+            name,
+            ty,
+            default,
+            docs,
+            attributes,
+            span,
+        } = self;
+
+        visibility.shift(delta);
+        name.shift(delta);
+        ty.shift(delta);
+        default.shift(delta);
+        docs.shift(delta);
+        attributes.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for SelfArgument {
+    fn shift(&mut self, delta: isize) {
+        self.span.shift(delta);
+    }
+}
+
+impl Shift for Method {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            visibility,
+            name,
+            generics,
+            self_argument,
+            arguments,
+            return_ty,
+            body,
+            docs,
+            attributes,
+            span,
+        } = self;
+
+        visibility.shift(delta);
+        name.shift(delta);
+        generics.shift(delta);
+        self_argument.shift(delta);
+        arguments.shift(delta);
+        return_ty.shift(delta);
+        body.shift(delta);
+        docs.shift(delta);
+        attributes.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Class {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            visibility,
+            name,
+            generics,
+            fields,
+            methods,
+            docs,
+            attributes,
+            span,
+        } = self;
+
+        visibility.shift(delta);
+        name.shift(delta);
+        generics.shift(delta);
+        fields.shift(delta);
+        methods.shift(delta);
+        docs.shift(delta);
+        attributes.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Variant {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            name,
+            kind,
+            docs,
+            attributes,
+            span,
+        } = self;
+
+        name.shift(delta);
+
+        match kind {
+            VariantKind::Unit => {}
+            VariantKind::Tuple(types) => types.shift(delta),
+            VariantKind::Struct(fields) => fields.shift(delta),
+        }
+
+        docs.shift(delta);
+        attributes.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for Enum {
+    fn shift(&mut self, delta: isize) {
+        let Self {
+            visibility,
+            name,
+            generics,
+            variants,
+            docs,
+            attributes,
+            span,
+        } = self;
+
+        visibility.shift(delta);
+        name.shift(delta);
+        generics.shift(delta);
+        variants.shift(delta);
+        docs.shift(delta);
+        attributes.shift(delta);
+        span.shift(delta);
+    }
+}
+
+impl Shift for UseTree {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Path { path, alias } => {
+                path.shift(delta);
+                alias.shift(delta);
+            }
+            Self::Glob { prefix } => prefix.shift(delta),
+            Self::Group { prefix, trees } => {
+                prefix.shift(delta);
+                trees.shift(delta);
+            }
+        }
+    }
+}
+
+impl Shift for Decl {
+    fn shift(&mut self, delta: isize) {
+        match self {
+            Self::Function(FunctionDecl {
+                visibility,
+                name,
+                generics,
+                arguments,
+                return_type,
+                body,
+                docs,
+                attributes,
+                span,
+            }) => {
+                visibility.shift(delta);
+                name.shift(delta);
+                generics.shift(delta);
+                arguments.shift(delta);
+                return_type.shift(delta);
+                body.shift(delta);
+                docs.shift(delta);
+                attributes.shift(delta);
+                span.shift(delta);
+            }
+            Self::Class(class) => class.shift(delta),
+            Self::Enum(enumeration) => enumeration.shift(delta),
+            Self::Module(ModuleDecl {
+                visibility,
+                name,
+                decls,
+                docs,
+                attributes,
+                span,
+            }) => {
+                visibility.shift(delta);
+                name.shift(delta);
+                decls.shift(delta);
+                docs.shift(delta);
+                attributes.shift(delta);
+                span.shift(delta);
+            }
+            Self::Use(UseDecl { tree, span }) => {
+                tree.shift(delta);
+                span.shift(delta);
+            }
+            Self::Const(ConstDecl {
+                visibility,
+                name,
+                ty,
+                value,
+                docs,
+                attributes,
+                span,
+            })
+            | Self::Static(StaticDecl {
+                visibility,
+                name,
+                ty,
+                value,
+                docs,
+                attributes,
+                span,
+            }) => {
+                visibility.shift(delta);
+                name.shift(delta);
+                ty.shift(delta);
+                value.shift(delta);
+                docs.shift(delta);
+                attributes.shift(delta);
+                span.shift(delta);
+            }
+            Self::Error(span) => span.shift(delta),
+        }
+    }
+}
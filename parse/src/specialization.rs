@@ -0,0 +1,15 @@
+use athame::{error::Error, specialization::Spec, ty::Type};
+
+use crate::{
+    parser::{Parse, Parser},
+    symbol::Symbol,
+};
+
+impl Parse for Spec {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        parser.expect(Symbol::Lt)?;
+        let generics = parser.parse_list::<Type>(Symbol::Gt)?;
+
+        Ok(Self { generics })
+    }
+}
@@ -0,0 +1,134 @@
+use athame::{
+    error::Error,
+    expr::Expr,
+    stmt::{LetStmt, ReturnStmt, Stmt, StmtKind},
+};
+
+use crate::{
+    attribute::docs_and_attributes,
+    expr::{block_like, is_block_like, is_expr_end},
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    symbol::Symbol,
+    token::TokenKind,
+};
+
+impl Parse for LetStmt {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::Let)?;
+
+        let name = parser.parse()?;
+
+        let ty = match parser.next_is(Symbol::Colon) {
+            true => Some(parser.parse()?),
+            false => None,
+        };
+
+        let expr = match parser.next_is(Symbol::Eq) {
+            true => Some(parser.parse()?),
+            false => None,
+        };
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self {
+            name,
+            ty,
+            expr,
+            span,
+        })
+    }
+}
+
+impl Parse for ReturnStmt {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let start = parser.span();
+        parser.expect(Keyword::Return)?;
+
+        let expr = match is_expr_end(parser.try_peek()) {
+            true => None,
+            false => Some(parser.parse()?),
+        };
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self { expr, span })
+    }
+}
+
+/// Expects the `;` ending a statement, pointing at the end of the statement if it's
+/// missing.
+fn expect_semi(parser: &mut Parser<'_>, stmt: &str) -> Result<(), Error> {
+    if parser.next_is(Symbol::Semi) {
+        return Ok(());
+    }
+
+    let span = parser.previous_span();
+    let found = match parser.try_peek() {
+        Some(token) => format!("'{}'", token.kind),
+        None => String::from("end of file"),
+    };
+
+    let error = Error::new(format!("expected ';' after {}", stmt))
+        .with_span(span)
+        .with_note(format!("found {} after it", found));
+
+    Err(error)
+}
+
+impl Parse for Stmt {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let (docs, attributes) = docs_and_attributes(parser)?;
+        let start = parser.span();
+
+        // doc comments only document declarations, but are harmless before statements
+        if let (Some(first), Some(last)) = (docs.first(), docs.last()) {
+            let warning = Error::new("unused doc comment")
+                .with_span(first.span.with(last.span))
+                .with_note("doc comments document declarations, not statements");
+
+            parser.warn(warning);
+        }
+
+        let token = parser.peek()?;
+        let is_block_like = is_block_like(token);
+
+        let kind = match token.kind {
+            TokenKind::Keyword(Keyword::Let) => {
+                let stmt = parser.parse()?;
+                expect_semi(parser, "let statement")?;
+                StmtKind::Let(stmt)
+            }
+            TokenKind::Keyword(Keyword::Return) => {
+                let stmt = parser.parse()?;
+                expect_semi(parser, "return statement")?;
+                StmtKind::Return(stmt)
+            }
+            // expressions ending with a block end the statement, and need no `;`
+            _ if is_block_like => {
+                let expr = block_like(parser)?;
+                parser.next_is(Symbol::Semi);
+                StmtKind::Expr(expr)
+            }
+            _ => {
+                let expr = parser.parse::<Expr>()?;
+
+                // the last expression of a block needs no `;`, it's the value of the block
+                if !matches!(parser.try_peek(), Some(token) if token.kind == Symbol::RightBrace) {
+                    expect_semi(parser, "expression")?;
+                }
+
+                StmtKind::Expr(expr)
+            }
+        };
+
+        let span = start.with(parser.previous_span());
+
+        Ok(Self {
+            kind,
+            attributes,
+            span,
+        })
+    }
+}
@@ -1,6 +1,11 @@
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Symbol {
-    /* Multi-character symbols */
+    /* Three-character symbols */
+    DotDotEq,
+    ShlEq,
+    ShrEq,
+
+    /* Two-character symbols */
     Arrow,
     FatArrow,
     EqEq,
@@ -9,6 +14,18 @@ pub enum Symbol {
     GtEq,
     AndAnd,
     OrOr,
+    ColonColon,
+    DotDot,
+    PlusEq,
+    MinusEq,
+    StarEq,
+    SlashEq,
+    PercentEq,
+    CaretEq,
+    AmpEq,
+    PipeEq,
+    Shl,
+    Shr,
 
     /* Single-character symbols */
     LeftParen,
@@ -30,49 +47,142 @@ pub enum Symbol {
     Amp,
     Dot,
     Comma,
+    Colon,
+    Semi,
+    Question,
     Pipe,
     Tilde,
     At,
     Hash,
 }
 
+/// Every symbol, ordered from longest to shortest so the first match is the longest.
+const SYMBOLS: &[Symbol] = &[
+    /* Three-character symbols */
+    Symbol::DotDotEq,
+    Symbol::ShlEq,
+    Symbol::ShrEq,
+    /* Two-character symbols */
+    Symbol::Arrow,
+    Symbol::FatArrow,
+    Symbol::EqEq,
+    Symbol::NotEq,
+    Symbol::LtEq,
+    Symbol::GtEq,
+    Symbol::AndAnd,
+    Symbol::OrOr,
+    Symbol::ColonColon,
+    Symbol::DotDot,
+    Symbol::PlusEq,
+    Symbol::MinusEq,
+    Symbol::StarEq,
+    Symbol::SlashEq,
+    Symbol::PercentEq,
+    Symbol::CaretEq,
+    Symbol::AmpEq,
+    Symbol::PipeEq,
+    Symbol::Shl,
+    Symbol::Shr,
+    /* Single-character symbols */
+    Symbol::LeftParen,
+    Symbol::RightParen,
+    Symbol::LeftBrace,
+    Symbol::RightBrace,
+    Symbol::LeftBracket,
+    Symbol::RightBracket,
+    Symbol::Plus,
+    Symbol::Minus,
+    Symbol::Star,
+    Symbol::Slash,
+    Symbol::Percent,
+    Symbol::Caret,
+    Symbol::Bang,
+    Symbol::Lt,
+    Symbol::Gt,
+    Symbol::Eq,
+    Symbol::Amp,
+    Symbol::Dot,
+    Symbol::Comma,
+    Symbol::Colon,
+    Symbol::Semi,
+    Symbol::Question,
+    Symbol::Pipe,
+    Symbol::Tilde,
+    Symbol::At,
+    Symbol::Hash,
+];
+
 impl Symbol {
-    pub fn from_parts(c: char, b: Option<char>) -> Option<Self> {
-        Some(match (c, b) {
-            /* Multi-character symbols */
-            ('-', Some('>')) => Self::Arrow,
-            ('=', Some('>')) => Self::FatArrow,
-            ('=', Some('=')) => Self::EqEq,
-            ('!', Some('=')) => Self::NotEq,
-            ('<', Some('=')) => Self::LtEq,
-            ('>', Some('=')) => Self::GtEq,
-            ('&', Some('&')) => Self::AndAnd,
-            ('|', Some('|')) => Self::OrOr,
+    /// Returns the longest symbol at the start of `s`.
+    pub fn from_prefix(s: &str) -> Option<Self> {
+        SYMBOLS
+            .iter()
+            .copied()
+            .find(|symbol| s.starts_with(symbol.as_str()))
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::DotDotEq => "..=",
+            Self::ShlEq => "<<=",
+            Self::ShrEq => ">>=",
+            Self::Arrow => "->",
+            Self::FatArrow => "=>",
+            Self::EqEq => "==",
+            Self::NotEq => "!=",
+            Self::LtEq => "<=",
+            Self::GtEq => ">=",
+            Self::AndAnd => "&&",
+            Self::OrOr => "||",
+            Self::ColonColon => "::",
+            Self::DotDot => "..",
+            Self::PlusEq => "+=",
+            Self::MinusEq => "-=",
+            Self::StarEq => "*=",
+            Self::SlashEq => "/=",
+            Self::PercentEq => "%=",
+            Self::CaretEq => "^=",
+            Self::AmpEq => "&=",
+            Self::PipeEq => "|=",
+            Self::Shl => "<<",
+            Self::Shr => ">>",
+            Self::LeftParen => "(",
+            Self::RightParen => ")",
+            Self::LeftBrace => "{",
+            Self::RightBrace => "}",
+            Self::LeftBracket => "[",
+            Self::RightBracket => "]",
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Star => "*",
+            Self::Slash => "/",
+            Self::Percent => "%",
+            Self::Caret => "^",
+            Self::Bang => "!",
+            Self::Lt => "<",
+            Self::Gt => ">",
+            Self::Eq => "=",
+            Self::Amp => "&",
+            Self::Dot => ".",
+            Self::Comma => ",",
+            Self::Colon => ":",
+            Self::Semi => ";",
+            Self::Question => "?",
+            Self::Pipe => "|",
+            Self::Tilde => "~",
+            Self::At => "@",
+            Self::Hash => "#",
+        }
+    }
 
-            /* Single-character symbols */
-            ('(', _) => Self::LeftParen,
-            (')', _) => Self::RightParen,
-            ('{', _) => Self::LeftBrace,
-            ('}', _) => Self::RightBrace,
-            ('[', _) => Self::LeftBracket,
-            (']', _) => Self::RightBracket,
-            ('+', _) => Self::Plus,
-            ('-', _) => Self::Minus,
-            ('*', _) => Self::Star,
-            ('/', _) => Self::Slash,
-            ('%', _) => Self::Percent,
-            ('^', _) => Self::Caret,
-            ('!', _) => Self::Bang,
-            ('<', _) => Self::Lt,
-            ('>', _) => Self::Gt,
-            ('=', _) => Self::Eq,
-            ('&', _) => Self::Amp,
-            ('.', _) => Self::Dot,
-            (',', _) => Self::Comma,
-            ('|', _) => Self::Pipe,
-            ('~', _) => Self::Tilde,
-            ('@', _) => Self::At,
-            ('#', _) => Self::Hash,
+    /// Splits a glued symbol into its first part and the rest, so that e.g. the
+    /// `>>` in `List<List<i32>>` can close two generic lists.
+    pub const fn split(&self) -> Option<(Self, Self)> {
+        Some(match self {
+            Self::Shr => (Self::Gt, Self::Gt),
+            Self::ShrEq => (Self::Gt, Self::GtEq),
+            Self::GtEq => (Self::Gt, Self::Eq),
+            Self::AndAnd => (Self::Amp, Self::Amp),
             _ => return None,
         })
     }
@@ -80,38 +190,6 @@ impl Symbol {
 
 impl std::fmt::Display for Symbol {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Symbol::Arrow => write!(f, "=>"),
-            Symbol::FatArrow => write!(f, "->"),
-            Symbol::EqEq => write!(f, "=="),
-            Symbol::NotEq => write!(f, "!="),
-            Symbol::LtEq => write!(f, "<="),
-            Symbol::GtEq => write!(f, ">="),
-            Symbol::AndAnd => write!(f, "&&"),
-            Symbol::OrOr => write!(f, "||"),
-            Symbol::LeftParen => write!(f, "("),
-            Symbol::RightParen => write!(f, ")"),
-            Symbol::LeftBrace => write!(f, "{{"),
-            Symbol::RightBrace => write!(f, "}}"),
-            Symbol::LeftBracket => write!(f, "["),
-            Symbol::RightBracket => write!(f, "]"),
-            Symbol::Plus => write!(f, "+"),
-            Symbol::Minus => write!(f, "-"),
-            Symbol::Star => write!(f, "*"),
-            Symbol::Slash => write!(f, "/"),
-            Symbol::Percent => write!(f, "%"),
-            Symbol::Caret => write!(f, "^"),
-            Symbol::Bang => write!(f, "!"),
-            Symbol::Lt => write!(f, "<"),
-            Symbol::Gt => write!(f, ">"),
-            Symbol::Eq => write!(f, "="),
-            Symbol::Amp => write!(f, "&"),
-            Symbol::Dot => write!(f, "."),
-            Symbol::Comma => write!(f, ","),
-            Symbol::Pipe => write!(f, "|"),
-            Symbol::Tilde => write!(f, "~"),
-            Symbol::At => write!(f, "@"),
-            Symbol::Hash => write!(f, "#"),
-        }
+        write!(f, "{}", self.as_str())
     }
 }
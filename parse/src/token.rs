@@ -1,16 +1,137 @@
-use athame::span::Span;
+use athame::{float::Float, int::Int, sources::Source, span::Span};
 
-use crate::{keyword::Keyword, symbol::Symbol};
+use crate::{
+    keyword::{ContextualKeyword, Keyword},
+    symbol::Symbol,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Radix {
+    Binary,
+    Octal,
+    Decimal,
+    Hexadecimal,
+}
+
+impl Radix {
+    pub const fn value(&self) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+        }
+    }
+
+    pub const fn prefix(&self) -> &'static str {
+        match self {
+            Self::Binary => "0b",
+            Self::Octal => "0o",
+            Self::Decimal => "",
+            Self::Hexadecimal => "0x",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntLiteral {
+    pub value: u128,
+    pub radix: Radix,
+    pub suffix: Option<Int>,
+}
+
+impl std::fmt::Display for IntLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.radix.prefix())?;
+
+        match self.radix {
+            Radix::Binary => write!(f, "{:b}", self.value)?,
+            Radix::Octal => write!(f, "{:o}", self.value)?,
+            Radix::Decimal => write!(f, "{}", self.value)?,
+            Radix::Hexadecimal => write!(f, "{:x}", self.value)?,
+        }
+
+        if let Some(suffix) = self.suffix {
+            write!(f, "{}", suffix)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatLiteral {
+    pub value: f64,
+    pub suffix: Option<Float>,
+}
+
+impl std::fmt::Display for FloatLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.value)?;
+
+        if let Some(suffix) = self.suffix {
+            write!(f, "{}", suffix)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    Whitespace,
+    LineComment,
+    BlockComment,
+}
+
+/// Source text between tokens, only kept by the lexer in lossless mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+impl Trivia {
+    pub const fn new(kind: TriviaKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    pub fn text<'a>(&self, source: &'a Source) -> &'a str {
+        &source.source()[self.span.index..self.span.end()]
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Token {
     pub kind: TokenKind,
     pub span: Span,
+    pub leading: Vec<Trivia>,
+    pub trailing: Vec<Trivia>,
 }
 
 impl Token {
     pub const fn new(kind: TokenKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            leading: Vec::new(),
+            trailing: Vec::new(),
+        }
+    }
+
+    /// The span of the token including its trivia.
+    pub fn full_span(&self) -> Span {
+        let mut span = self.span;
+
+        if let Some(trivia) = self.leading.first() {
+            span = span.with(trivia.span);
+        }
+
+        if let Some(trivia) = self.trailing.last() {
+            span = span.with(trivia.span);
+        }
+
+        span
     }
 }
 
@@ -20,11 +141,45 @@ impl std::fmt::Display for Token {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DocStyle {
+    /// `/// ...`, documents the following item.
+    Outer,
+    /// `//! ...`, documents the enclosing module.
+    Inner,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocComment {
+    pub style: DocStyle,
+    pub text: String,
+}
+
+impl std::fmt::Display for DocComment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.style {
+            DocStyle::Outer => write!(f, "///{}", self.text),
+            DocStyle::Inner => write!(f, "//!{}", self.text),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum TokenKind {
     Ident(String),
+    /// A loop label, `'name`, without the `'`.
+    Label(String),
     Symbol(Symbol),
     Keyword(Keyword),
+    Int(IntLiteral),
+    Float(FloatLiteral),
+    String(String),
+    Char(char),
+    Byte(u8),
+    ByteString(Vec<u8>),
+    DocComment(DocComment),
+    Error,
+    Eof,
 }
 
 impl PartialEq<Symbol> for TokenKind {
@@ -45,12 +200,31 @@ impl PartialEq<Keyword> for TokenKind {
     }
 }
 
+impl PartialEq<ContextualKeyword> for TokenKind {
+    fn eq(&self, other: &ContextualKeyword) -> bool {
+        match self {
+            Self::Ident(ident) => ident == other.as_str(),
+            _ => false,
+        }
+    }
+}
+
 impl std::fmt::Display for TokenKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Ident(ident) => write!(f, "{}", ident),
+            Self::Label(label) => write!(f, "'{}", label),
             Self::Symbol(symbol) => write!(f, "{}", symbol),
             Self::Keyword(keyword) => write!(f, "{}", keyword),
+            Self::Int(int) => write!(f, "{}", int),
+            Self::Float(float) => write!(f, "{}", float),
+            Self::String(string) => write!(f, "{:?}", string),
+            Self::Char(c) => write!(f, "{:?}", c),
+            Self::Byte(byte) => write!(f, "b'{}'", byte.escape_ascii()),
+            Self::ByteString(bytes) => write!(f, "b\"{}\"", bytes.escape_ascii()),
+            Self::DocComment(doc) => write!(f, "{}", doc),
+            Self::Error => write!(f, "invalid token"),
+            Self::Eof => write!(f, "end of file"),
         }
     }
 }
@@ -0,0 +1,131 @@
+use athame::{
+    error::Error,
+    float::Float,
+    function::Signature,
+    int::Int,
+    path::{Path, PathSegment},
+    specialization::SpecGenerics,
+    ty::Type,
+};
+
+use crate::{
+    error::Expected,
+    keyword::Keyword,
+    parser::{Parse, Parser},
+    path::type_path,
+    symbol::Symbol,
+    token::TokenKind,
+};
+
+/// The type named `name`, if it is a primitive type.
+pub fn primitive(name: &str) -> Option<Type> {
+    match name {
+        "void" => Some(Type::Void),
+        "bool" => Some(Type::Bool),
+        "i8" => Some(Type::Int(Int::i8())),
+        "i16" => Some(Type::Int(Int::i16())),
+        "i32" => Some(Type::Int(Int::i32())),
+        "i64" => Some(Type::Int(Int::i64())),
+        "i128" => Some(Type::Int(Int::i128())),
+        "isize" => Some(Type::Int(Int::isize())),
+        "u8" => Some(Type::Int(Int::u8())),
+        "u16" => Some(Type::Int(Int::u16())),
+        "u32" => Some(Type::Int(Int::u32())),
+        "u64" => Some(Type::Int(Int::u64())),
+        "u128" => Some(Type::Int(Int::u128())),
+        "usize" => Some(Type::Int(Int::usize())),
+        "f16" => Some(Type::Float(Float::f16())),
+        "f32" => Some(Type::Float(Float::f32())),
+        "f64" => Some(Type::Float(Float::f64())),
+        _ => None,
+    }
+}
+
+/// The primitive type `path` names, primitives are never absolute or generic.
+fn primitive_path(path: &Path) -> Option<Type> {
+    match path.segments.as_slice() {
+        [PathSegment::IdentSegment(segment)] if !path.is_absolute && segment.spec.is_empty() => {
+            primitive(&segment.name.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Parses `[T; N]`.
+fn array(parser: &mut Parser<'_>) -> Result<Type, Error> {
+    parser.expect(Symbol::LeftBracket)?;
+    let ty = parser.parse()?;
+    parser.expect(Symbol::Semi)?;
+
+    let token = parser.next()?;
+
+    let size = match token.kind {
+        TokenKind::Int(ref int) if int.suffix.is_none() => usize::try_from(int.value).ok(),
+        _ => None,
+    };
+
+    let Some(size) = size else {
+        let error = Error::new("expected array length")
+            .with_span(token.span)
+            .with_note("array lengths are integer literals without a suffix");
+
+        return Err(error);
+    };
+
+    parser.expect(Symbol::RightBracket)?;
+
+    Ok(Type::array(ty, size))
+}
+
+/// Parses `fn(A, B) -> C`.
+fn function(parser: &mut Parser<'_>) -> Result<Type, Error> {
+    parser.expect(Keyword::Fn)?;
+    parser.expect(Symbol::LeftParen)?;
+
+    let arguments = parser.parse_list(Symbol::RightParen)?;
+
+    let return_ty = match parser.next_is(Symbol::Arrow) {
+        true => parser.parse()?,
+        false => Type::Void,
+    };
+
+    Ok(Type::function(Signature {
+        generics: SpecGenerics::empty(),
+        arguments,
+        return_ty,
+    }))
+}
+
+impl Parse for Type {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        let token = parser.peek()?;
+
+        match token.kind {
+            TokenKind::Symbol(Symbol::Star) => {
+                parser.next()?;
+                Ok(Type::pointer(parser.parse()?))
+            }
+            TokenKind::Symbol(Symbol::LeftBracket) => array(parser),
+            TokenKind::Keyword(Keyword::Fn) => function(parser),
+            TokenKind::Ident(_)
+            | TokenKind::Symbol(Symbol::ColonColon)
+            | TokenKind::Keyword(Keyword::Self_ | Keyword::Super) => {
+                let path = type_path(parser)?;
+
+                match primitive_path(&path) {
+                    Some(ty) => Ok(ty),
+                    None => Ok(Type::Unresolved(path)),
+                }
+            }
+            _ => Err(parser.unexpected(&[
+                Expected::Ident,
+                Expected::Symbol(Symbol::ColonColon),
+                Expected::Keyword(Keyword::Self_),
+                Expected::Keyword(Keyword::Super),
+                Expected::Symbol(Symbol::Star),
+                Expected::Symbol(Symbol::LeftBracket),
+                Expected::Keyword(Keyword::Fn),
+            ])),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use athame::{error::Error, visibility::Visibility};
+
+use crate::{
+    keyword::Keyword,
+    parser::{Parse, Parser},
+};
+
+impl Parse for Visibility {
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, Error> {
+        match parser.next_is(Keyword::Pub) {
+            true => Ok(Self::Public(parser.previous_span())),
+            false => Ok(Self::Private),
+        }
+    }
+}
+
+/// Fails if `visibility` is `pub`, for items that don't support it yet.
+pub fn no_visibility(visibility: Visibility, item: &str) -> Result<(), Error> {
+    match visibility {
+        Visibility::Public(span) => {
+            Err(Error::new(format!("'pub' is not supported on {}", item)).with_span(span))
+        }
+        Visibility::Private => Ok(()),
+    }
+}
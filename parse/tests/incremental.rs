@@ -0,0 +1,213 @@
+use athame::sources::{Source, SourceId};
+use athame_parse::{
+    incremental::{ParsedProgram, TextEdit},
+    lexer::{join_tokens, Lexer},
+    token::Token,
+};
+
+const SOURCES: &[&str] = &[
+    "",
+    "a",
+    "fn main() {\n    let x = 1 + 2;\n    x * 3\n}\n",
+    "a // comment\nb /* block */ c\n",
+    "\"string\" 'c' r#\"raw\"# b\"bytes\" 1.5e3 0xff_u8",
+    "/// doc\nfn f(a: i32) -> i32 { a >> 1 }",
+    "x.0 1..2 a::b",
+];
+
+const INSERTS: &[&str] = &[
+    "", "a", " ", "\n", "1", ".", "e", "/", "*", "\"", "'", "#", "r", "//", "/*", "*/",
+];
+
+fn lex(source: &Source, lossless: bool) -> Vec<Token> {
+    Lexer::new(source, SourceId::null())
+        .with_lossless(lossless)
+        .tokenize()
+}
+
+fn check(text: &str, edit: &TextEdit, lossless: bool) {
+    let source = Source::new("test.ath", text);
+    let tokens = lex(&source, lossless);
+
+    let edited = edit.apply(&source);
+    let relexed = Lexer::new(&edited, SourceId::null())
+        .with_lossless(lossless)
+        .relex(&tokens, edit);
+
+    assert_eq!(
+        relexed,
+        lex(&edited, lossless),
+        "source: {:?}, edit: {:?}",
+        text,
+        edit,
+    );
+
+    if lossless {
+        assert_eq!(join_tokens(&relexed, &edited), edited.source());
+    }
+}
+
+fn char_boundaries(text: &str) -> impl Iterator<Item = usize> + '_ {
+    (0..=text.len()).filter(|&i| text.is_char_boundary(i))
+}
+
+#[test]
+fn insertions_match_fresh_lex() {
+    for text in SOURCES {
+        for index in char_boundaries(text) {
+            for insert in INSERTS {
+                let edit = TextEdit::insert(index, *insert);
+
+                check(text, &edit, false);
+                check(text, &edit, true);
+            }
+        }
+    }
+}
+
+#[test]
+fn deletions_match_fresh_lex() {
+    for text in SOURCES {
+        for start in char_boundaries(text) {
+            for end in char_boundaries(text).filter(|&end| end >= start).take(4) {
+                let edit = TextEdit::delete(start..end);
+
+                check(text, &edit, false);
+                check(text, &edit, true);
+            }
+        }
+    }
+}
+
+#[test]
+fn replacements_match_fresh_lex() {
+    let text = SOURCES[2];
+
+    for start in char_boundaries(text) {
+        for end in char_boundaries(text).filter(|&end| end >= start).take(3) {
+            for insert in INSERTS {
+                let edit = TextEdit::new(start..end, *insert);
+
+                check(text, &edit, false);
+                check(text, &edit, true);
+            }
+        }
+    }
+}
+
+#[test]
+fn tokens_after_edit_are_moved() {
+    let source = Source::new("test.ath", "a b c");
+    let tokens = lex(&source, false);
+
+    let edit = TextEdit::new(0..1, "abc");
+    let edited = edit.apply(&source);
+    let relexed = Lexer::new(&edited, SourceId::null()).relex(&tokens, &edit);
+
+    let spans: Vec<_> = relexed.iter().map(|t| t.span.index).collect();
+    assert_eq!(spans, [0, 4, 6]);
+}
+
+#[test]
+fn unterminated_chars_are_relexed_up_to_the_end_of_the_line() {
+    // the unterminated literal looks for its closing quote far past its end
+    let edit = TextEdit::new(13..14, "'");
+
+    check("a '+ bcdefgh x", &edit, false);
+    check("a '+ bcdefgh x", &edit, true);
+    check("a b'+ bcdefgh x\ny", &edit, false);
+}
+
+const PROGRAMS: &[&str] = &[
+    "//! inner\nfn a() { let x = 1; }\nfn b(y: i32) -> i32 { y * 2 }\n",
+    "use a::{b, c};\nconst N: i32 = 4;\n/// doc\n#[inline] fn f() { if N { 1 } }\n",
+    "class C { pub x: i32, fn get(self) -> i32 { self.x } }\nenum E { A, B(i32) }\n",
+    "mod m { fn f() { g(1, 2) } }\nfn broken( { 0xz }\nfn after() { 1.5 }\n",
+    "fn a() { let a = 1 }\nstatic S: u8 = 256u8;\nfn b() { /* note */ a }\n",
+    "fn a() { 'x bcdefgh }\nfn b() { '+ c } fn c() { d }\n",
+    "fn a() { let a = 1; }\nfn b() { let а = 2; }\nfn с() { а + c }\n",
+];
+
+/// Checks that parsing again after `edit` gives the same result as parsing the
+/// edited source, returning the result.
+fn check_reparse(parsed: &ParsedProgram, source: &Source, edit: &TextEdit) -> ParsedProgram {
+    let edited = edit.apply(source);
+    let reparsed = parsed.reparse(&edited, edit);
+    let fresh = ParsedProgram::parse(&edited, SourceId::null());
+
+    // `Debug` includes the spans of identifiers, which `PartialEq` ignores
+    assert_eq!(
+        format!("{:?}", reparsed.program),
+        format!("{:?}", fresh.program),
+        "source: {:?}, edit: {:?}",
+        source.source(),
+        edit,
+    );
+    assert_eq!(
+        reparsed.errors,
+        fresh.errors,
+        "source: {:?}, edit: {:?}",
+        source.source(),
+        edit,
+    );
+
+    reparsed
+}
+
+#[test]
+fn reparse_matches_fresh_parse() {
+    for text in PROGRAMS {
+        let source = Source::new("test.ath", *text);
+        let parsed = ParsedProgram::parse(&source, SourceId::null());
+
+        for start in char_boundaries(text) {
+            for insert in INSERTS.iter().chain(&["}", "{", ";", "fn x() {}", "а"]) {
+                check_reparse(&parsed, &source, &TextEdit::insert(start, *insert));
+            }
+
+            for end in char_boundaries(text).filter(|&end| end > start).take(3) {
+                check_reparse(&parsed, &source, &TextEdit::delete(start..end));
+            }
+        }
+    }
+}
+
+#[test]
+fn reparse_checks_confusables_against_the_entire_program() {
+    let source = Source::new("test.ath", "fn a(){let a=1;} fn b(){let x=2;}");
+    let parsed = ParsedProgram::parse(&source, SourceId::null());
+
+    let edit = TextEdit::new(28..29, "а");
+    let reparsed = check_reparse(&parsed, &source, &edit);
+
+    let warnings: Vec<_> = reparsed
+        .errors
+        .warnings()
+        .iter()
+        .map(|w| w.title())
+        .collect();
+    assert_eq!(warnings, ["identifier 'а' is confusable with 'a'"]);
+}
+
+#[test]
+fn repeated_reparses_match_fresh_parse() {
+    let mut source = Source::new("test.ath", PROGRAMS[3]);
+    let mut parsed = ParsedProgram::parse(&source, SourceId::null());
+
+    // each edit replaces the first occurrence of a pattern in the current source
+    let edits = [
+        ("mod", "fn first() {}\nmod"),
+        ("broken(", "broken()"),
+        ("0xz", "0xf"),
+        ("fn first() {}\n", ""),
+        ("1.5 }", "1.5 }\nfn last() { {"),
+    ];
+
+    for (pattern, text) in edits {
+        let start = source.source().find(pattern).unwrap();
+        let edit = TextEdit::new(start..start + pattern.len(), text);
+
+        parsed = check_reparse(&parsed, &source, &edit);
+        source = edit.apply(&source);
+    }
+}
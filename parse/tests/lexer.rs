@@ -0,0 +1,268 @@
+use athame::{
+    float::Float,
+    int::Int,
+    sources::{Source, SourceId},
+};
+use athame_parse::{
+    lexer::Lexer,
+    symbol::Symbol,
+    token::{Radix, TokenKind},
+};
+
+/// Lexes `text`, returning the token kinds and the titles and byte ranges of the
+/// errors.
+fn lex(text: &str) -> (Vec<TokenKind>, Vec<(String, usize, usize)>) {
+    let source = Source::new("test.ath", text);
+    let mut lexer = Lexer::new(&source, SourceId::null());
+
+    let kinds: Vec<_> = lexer.tokenize().into_iter().map(|t| t.kind).collect();
+
+    let errors = lexer
+        .errors()
+        .errors()
+        .iter()
+        .map(|e| {
+            let span = e.span();
+            (e.title().to_string(), span.index, span.index + span.length)
+        })
+        .collect();
+
+    (kinds, errors)
+}
+
+fn int(text: &str) -> (u128, Radix, Option<Int>) {
+    match lex(text) {
+        (kinds, errors) if errors.is_empty() => match kinds.as_slice() {
+            [TokenKind::Int(int)] => (int.value, int.radix, int.suffix),
+            kinds => panic!("expected a single int for {:?}, found {:?}", text, kinds),
+        },
+        (_, errors) => panic!("unexpected errors for {:?}: {:?}", text, errors),
+    }
+}
+
+fn float(text: &str) -> (f64, Option<Float>) {
+    match lex(text) {
+        (kinds, errors) if errors.is_empty() => match kinds.as_slice() {
+            [TokenKind::Float(float)] => (float.value, float.suffix),
+            kinds => panic!("expected a single float for {:?}, found {:?}", text, kinds),
+        },
+        (_, errors) => panic!("unexpected errors for {:?}: {:?}", text, errors),
+    }
+}
+
+/// The title of the only error lexing `text` reports, with its byte range.
+fn error(text: &str) -> (String, usize, usize) {
+    let (_, mut errors) = lex(text);
+
+    assert_eq!(errors.len(), 1, "errors for {:?}: {:?}", text, errors);
+    errors.pop().unwrap()
+}
+
+#[test]
+fn int_literals() {
+    assert_eq!(int("1_000"), (1000, Radix::Decimal, None));
+    assert_eq!(int("0x1f"), (31, Radix::Hexadecimal, None));
+    assert_eq!(int("0o17"), (15, Radix::Octal, None));
+    assert_eq!(int("0b1010_u8"), (10, Radix::Binary, Some(Int::u8())));
+    assert_eq!(int("3isize").2, Some(Int::isize()));
+}
+
+#[test]
+fn float_literals() {
+    assert_eq!(float("1.5"), (1.5, None));
+    assert_eq!(float(".5"), (0.5, None));
+    assert_eq!(float("1e9"), (1e9, None));
+    assert_eq!(float("2.5e-3"), (2.5e-3, None));
+    assert_eq!(float("1.5f32"), (1.5, Some(Float::f32())));
+    assert_eq!(float("2f64"), (2.0, Some(Float::f64())));
+}
+
+#[test]
+fn suffix_ranges() {
+    assert_eq!(int("255u8").0, 255);
+    assert_eq!(int("0xffff_u16").0, 0xffff);
+    assert_eq!(
+        int("340282366920938463463374607431768211455u128").0,
+        u128::MAX
+    );
+    // signed literals may reach the magnitude of the minimum value, for negation
+    assert_eq!(int("128i8").0, 128);
+
+    assert_eq!(error("256u8").0, "literal out of range for 'u8'");
+    assert_eq!(error("129i8").0, "literal out of range for 'i8'");
+    assert_eq!(error("0x1_0000u16").0, "literal out of range for 'u16'");
+    assert_eq!(error("1e40f32").0, "literal out of range for 'f32'");
+    assert_eq!(error("70000f16").0, "literal out of range for 'f16'");
+    assert_eq!(
+        error("340282366920938463463374607431768211456").0,
+        "integer literal is too large"
+    );
+}
+
+#[test]
+fn out_of_range_notes_give_the_maximum() {
+    let note = |text: &str| {
+        let source = Source::new("test.ath", text);
+        let mut lexer = Lexer::new(&source, SourceId::null());
+        lexer.tokenize();
+
+        let error = &lexer.errors().errors()[0];
+        error.notes()[0].note().to_string()
+    };
+
+    assert_eq!(note("200i8"), "the maximum value of 'i8' is 127");
+    assert_eq!(note("256u8"), "the maximum value of 'u8' is 255");
+}
+
+#[test]
+fn invalid_suffixes() {
+    assert_eq!(
+        error("10xyz"),
+        ("invalid suffix 'xyz' for number literal".into(), 2, 5)
+    );
+    assert_eq!(error("1.5u8").0, "invalid suffix 'u8' for float literal");
+    assert_eq!(error("0b1f32").0, "base 2 float literals are not supported");
+}
+
+#[test]
+fn radix_errors() {
+    assert_eq!(
+        error("0b102"),
+        ("invalid digit '2' in base 2 literal".into(), 4, 5)
+    );
+    assert_eq!(
+        error("0o8"),
+        ("invalid digit '8' in base 8 literal".into(), 2, 3)
+    );
+    assert_eq!(
+        error("0x").0,
+        "expected at least one digit in base 16 literal"
+    );
+    assert_eq!(
+        error("0x.5").0,
+        "expected at least one digit in base 16 literal"
+    );
+    assert_eq!(
+        error("0b").0,
+        "expected at least one digit in base 2 literal"
+    );
+}
+
+fn string(text: &str) -> String {
+    match lex(text) {
+        (kinds, errors) if errors.is_empty() => match kinds.as_slice() {
+            [TokenKind::String(string)] => string.clone(),
+            kinds => panic!("expected a single string for {:?}, found {:?}", text, kinds),
+        },
+        (_, errors) => panic!("unexpected errors for {:?}: {:?}", text, errors),
+    }
+}
+
+#[test]
+fn escapes() {
+    assert_eq!(string(r#""a\nb\t\\\"\0""#), "a\nb\t\\\"\0");
+    assert_eq!(string(r#""\x7f\u{1F600}""#), "\x7f\u{1F600}");
+    assert_eq!(string(r##"r#"raw \n "quoted""#"##), r#"raw \n "quoted""#);
+    assert_eq!(lex(r"'\n'").0, [TokenKind::Char('\n')]);
+    assert_eq!(lex(r"b'\x80'").0, [TokenKind::Byte(0x80)]);
+    assert_eq!(
+        lex(r#"b"a\xff""#).0,
+        [TokenKind::ByteString(vec![b'a', 0xff])]
+    );
+}
+
+#[test]
+fn escape_errors_point_at_the_escape() {
+    let (_, start, end) = error(r#""ok \q""#);
+    assert_eq!(start..end, 4..6);
+    assert_eq!(error(r#""\x80""#).1, 1);
+    assert_eq!(error(r#""\u{110000}""#).1, 1);
+    assert_eq!(error("\"unterminated").1, 0);
+}
+
+#[test]
+fn symbols_use_the_longest_match() {
+    let (kinds, errors) = lex("a::b ..= .. . <<= << < >>= -> => ! != += ?");
+    assert!(errors.is_empty());
+
+    let symbols: Vec<_> = kinds
+        .into_iter()
+        .filter_map(|kind| match kind {
+            TokenKind::Symbol(symbol) => Some(symbol),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(
+        symbols,
+        [
+            Symbol::ColonColon,
+            Symbol::DotDotEq,
+            Symbol::DotDot,
+            Symbol::Dot,
+            Symbol::ShlEq,
+            Symbol::Shl,
+            Symbol::Lt,
+            Symbol::ShrEq,
+            Symbol::Arrow,
+            Symbol::FatArrow,
+            Symbol::Bang,
+            Symbol::NotEq,
+            Symbol::PlusEq,
+            Symbol::Question,
+        ]
+    );
+}
+
+#[test]
+fn symbol_display() {
+    assert_eq!(Symbol::Arrow.to_string(), "->");
+    assert_eq!(Symbol::FatArrow.to_string(), "=>");
+    assert_eq!(Symbol::ShrEq.to_string(), ">>=");
+}
+
+#[test]
+fn confusable_idents_point_at_the_first_use() {
+    let source = Source::new("test.ath", "let a = 1;\nlet а = 2;\nlet а = 3;");
+    let mut lexer = Lexer::new(&source, SourceId::null());
+    lexer.tokenize();
+
+    let warnings = lexer.errors().warnings();
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert_eq!(warnings[0].title(), "identifier 'а' is confusable with 'a'");
+    assert_eq!(warnings[0].span().index, 15);
+    assert!(format!("{:?}", warnings[0]).contains("'a' is used at 1:4"));
+}
+
+#[test]
+fn raw_idents_may_be_keywords_and_reserved_words() {
+    let ident = |name: &str| TokenKind::Ident(String::from(name));
+
+    assert_eq!(
+        lex("r#match r#type r#x"),
+        (vec![ident("match"), ident("type"), ident("x")], vec![])
+    );
+
+    assert_eq!(
+        error("r#self"),
+        (String::from("'self' cannot be a raw identifier"), 0, 6)
+    );
+    assert_eq!(
+        error("r#crate"),
+        (String::from("'crate' cannot be a raw identifier"), 0, 7)
+    );
+}
+
+#[test]
+fn reserved_words_are_identifiers_with_an_error() {
+    let (kinds, errors) = lex("let type = 1");
+
+    assert_eq!(kinds[1], TokenKind::Ident(String::from("type")));
+    assert_eq!(errors, [(String::from("'type' is a reserved word"), 4, 8)]);
+
+    // contextual keywords are identifiers everywhere
+    assert_eq!(
+        lex("crate"),
+        (vec![TokenKind::Ident(String::from("crate"))], vec![])
+    );
+}
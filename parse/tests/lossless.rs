@@ -0,0 +1,82 @@
+use athame::sources::{Source, SourceId};
+use athame_parse::{
+    lexer::{join_tokens, Lexer},
+    token::{Token, TokenKind, TriviaKind},
+};
+
+const INPUTS: &[&str] = &[
+    "",
+    " ",
+    "\n\n",
+    "a",
+    "  a  ",
+    "a\n",
+    "\r\na\r\n",
+    "a + b * (c - 1)",
+    "// only a comment",
+    "/* only a block comment */",
+    "a // trailing comment\nb",
+    "a /* inline */ b /* multi\nline */ c",
+    "/* outer /* nested */ still outer */ x",
+    "/// doc comment\nx\n//! inner doc\n",
+    "\t\tindented\n\t\t\tmore  ",
+    "'c' \"string\\n\" r#\"raw\"# b'x' b\"bytes\"",
+    "0x1f_u8 1.5e3 2.5f32 10 0b1010",
+    "a\n// comment at the end",
+    "a /* comment at the end */",
+    "é ñ  ü\n",
+    "a $ b ` c",
+    "'\\q' \"bad \\q escape\" x",
+    "0b102 1.5u8 10xyz y",
+    "\"unterminated",
+    "/* unterminated",
+];
+
+fn lex(text: &str) -> (Source, Vec<Token>) {
+    let source = Source::new("test.ath", text);
+    let tokens = Lexer::new(&source, SourceId::null())
+        .with_lossless(true)
+        .tokenize();
+
+    (source, tokens)
+}
+
+#[test]
+fn round_trip() {
+    for input in INPUTS {
+        let (source, tokens) = lex(input);
+
+        assert_eq!(join_tokens(&tokens, &source), *input, "input: {:?}", input);
+    }
+}
+
+#[test]
+fn ends_with_eof() {
+    for input in INPUTS {
+        let (_, tokens) = lex(input);
+
+        assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+    }
+}
+
+#[test]
+fn trailing_trivia_stops_at_line_break() {
+    let (_, tokens) = lex("a // comment\nb");
+
+    let kinds: Vec<_> = tokens[0].trailing.iter().map(|t| t.kind).collect();
+    assert_eq!(kinds, [TriviaKind::Whitespace, TriviaKind::LineComment]);
+
+    let kinds: Vec<_> = tokens[1].leading.iter().map(|t| t.kind).collect();
+    assert_eq!(kinds, [TriviaKind::Whitespace]);
+}
+
+#[test]
+fn default_mode_has_no_trivia() {
+    let source = Source::new("test.ath", " a /* b */ c ");
+    let tokens = Lexer::new(&source, SourceId::null()).tokenize();
+
+    assert_eq!(tokens.len(), 2);
+    assert!(tokens
+        .iter()
+        .all(|t| t.leading.is_empty() && t.trailing.is_empty()));
+}
@@ -0,0 +1,174 @@
+use athame::{
+    data::{Data, ModuleId},
+    ident::Ident,
+    sources::{Source, Sources},
+    span::Span,
+};
+use athame_parse::parse_program;
+
+/// Parses `text` and adds it to a new [`Data`], returning the root module with the
+/// titles of the errors.
+fn build(text: &str) -> (Data, ModuleId, Vec<String>) {
+    let mut sources = Sources::new();
+    let id = sources.add(Source::new("test.ath", text));
+
+    let (program, mut errors) = parse_program(&sources, id);
+
+    let mut data = Data::default();
+    let root = data.add_program(&program, Ident::new("test", Span::null()), &mut errors);
+
+    let errors = errors
+        .errors()
+        .iter()
+        .map(|e| e.title().to_string())
+        .collect();
+
+    (data, root, errors)
+}
+
+fn errors(text: &str) -> Vec<String> {
+    build(text).2
+}
+
+/// The names of the functions in `module`, sorted.
+fn functions(data: &Data, module: ModuleId) -> Vec<String> {
+    let functions = data.modules[module].functions.keys();
+    let mut names: Vec<_> = functions.map(|name| name.to_string()).collect();
+    names.sort();
+    names
+}
+
+/// The child module of `module` named `name`.
+fn child(data: &Data, module: ModuleId, name: &str) -> ModuleId {
+    data.modules[module].modules[&Ident::new(name, Span::null())].id
+}
+
+#[test]
+fn private_items_are_only_imported_inside_their_module() {
+    assert_eq!(
+        errors("mod a { fn secret() {} pub fn open() {} }\nuse a::secret;\nuse a::open;"),
+        ["'secret' is private"]
+    );
+
+    assert!(errors("fn secret() {}\nmod a { use super::secret; }").is_empty());
+    assert!(errors("mod a { fn secret() {} mod b { use super::secret; } }").is_empty());
+}
+
+#[test]
+fn private_modules_are_only_visible_inside_their_parent() {
+    assert_eq!(
+        errors("mod a { mod b { pub fn x() {} } }\nuse a::b::x;"),
+        ["'b' is private"]
+    );
+    assert_eq!(
+        errors("mod a { mod b { pub fn x() {} } }\nuse a::b::*;"),
+        ["'b' is private"]
+    );
+
+    assert!(errors("mod a { mod b { pub fn x() {} } use b::x; }").is_empty());
+    assert!(errors("mod a { pub mod b { pub fn x() {} } }\nuse a::b::x;").is_empty());
+}
+
+#[test]
+fn imports_are_private_to_the_importing_module() {
+    assert_eq!(
+        errors("mod a { pub fn x() {} }\nmod b { use super::a::x; }\nuse b::x;"),
+        ["'x' is private"]
+    );
+}
+
+#[test]
+fn globs_only_import_public_names_from_outside() {
+    let (data, root, errors) =
+        build("mod a { pub fn x() {} fn y() {} mod b { use super::*; } }\nuse a::*;");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let a = child(&data, root, "a");
+    assert_eq!(functions(&data, root), ["x"]);
+    assert_eq!(functions(&data, child(&data, a, "b")), ["x", "y"]);
+}
+
+#[test]
+fn consts_and_statics_are_resolved() {
+    let (data, root, errors) =
+        build("mod a { pub const N: i32 = 1; pub static S: u8 = 2; }\nuse a::{N, S as T};");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let module = &data.modules[root];
+    let n = module.consts[&Ident::new("N", Span::null())].id;
+    let t = module.statics[&Ident::new("T", Span::null())].id;
+
+    assert_eq!(data.consts[n].name.as_str(), "N");
+    assert_eq!(data.statics[t].name.as_str(), "S");
+    assert!(data.consts[n].visibility.is_public());
+}
+
+#[test]
+fn modules_form_a_tree() {
+    let (data, root, errors) = build("mod a { mod b {} }\npub mod c {}");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let a = child(&data, root, "a");
+    let b = child(&data, a, "b");
+    let c = child(&data, root, "c");
+
+    assert_eq!(data.modules[root].parent, None);
+    assert_eq!(data.modules[a].parent, Some(root));
+    assert_eq!(data.modules[b].parent, Some(a));
+    assert_eq!(data.modules[b].name.as_str(), "b");
+    assert_eq!(data.root(b), root);
+
+    assert!(!data.modules[a].visibility.is_public());
+    assert!(data.modules[c].visibility.is_public());
+}
+
+#[test]
+fn use_trees_import_every_path() {
+    let (data, root, errors) = build(
+        "mod a { pub mod b { pub fn c() {} pub fn d() {} } pub fn e() {} }\nuse a::{b::{self, c as renamed}, e};\nmod m { use super::a::b::d; use crate::a::e; use self::d as f; }",
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let a = child(&data, root, "a");
+    assert_eq!(child(&data, root, "b"), child(&data, a, "b"));
+    assert_eq!(functions(&data, root), ["e", "renamed"]);
+    assert_eq!(functions(&data, child(&data, root, "m")), ["d", "e", "f"]);
+}
+
+#[test]
+fn explicit_imports_shadow_glob_imports() {
+    let text = "mod a { pub fn x() {} pub fn y() {} }\nmod b { pub fn x() {} }";
+
+    for imports in ["use a::*;\nuse b::x;", "use b::x;\nuse a::*;"] {
+        let (data, root, errors) = build(&format!("{}\n{}", text, imports));
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        let b = child(&data, root, "b");
+        let x = Ident::new("x", Span::null());
+
+        assert_eq!(functions(&data, root), ["x", "y"]);
+        assert_eq!(
+            data.modules[root].functions[&x].id,
+            data.modules[b].functions[&x].id
+        );
+    }
+}
+
+#[test]
+fn conflicting_and_missing_imports_are_reported() {
+    assert_eq!(
+        errors("mod a { pub fn x() {} }\nmod b { pub fn x() {} }\nuse a::x;\nuse b::x;"),
+        ["'x' is defined multiple times"]
+    );
+    assert_eq!(
+        errors("fn x() {}\nmod a { pub fn x() {} }\nuse a::x;"),
+        ["'x' is defined multiple times"]
+    );
+    assert_eq!(
+        errors("mod a {}\nuse a::missing;\nuse super::a;"),
+        [
+            "unresolved import 'a::missing'",
+            "unresolved import 'super::a'"
+        ]
+    );
+}
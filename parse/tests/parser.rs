@@ -0,0 +1,862 @@
+use athame::{
+    attribute::{Attribute, AttributeArg, AttributeStyle, AttributeValue},
+    decl::{Decl, FunctionDecl, UseTree},
+    expr::{BinOp, Expr, LiteralKind, UnaryOp},
+    float::Float,
+    int::Int,
+    path::PathSegment,
+    pattern::{Pattern, PatternKind},
+    program::Program,
+    sources::{Source, Sources},
+    span::Span,
+    stmt::{Stmt, StmtKind},
+    ty::Type,
+};
+use athame_parse::parse_program;
+
+/// Parses `text`, returning the program with the titles of the errors.
+fn parse(text: &str) -> (Program, Vec<String>) {
+    let mut sources = Sources::new();
+    let id = sources.add(Source::new("test.ath", text));
+
+    let (program, errors) = parse_program(&sources, id);
+    let errors = errors
+        .errors()
+        .iter()
+        .map(|e| e.title().to_string())
+        .collect();
+
+    (program, errors)
+}
+
+fn errors(text: &str) -> Vec<String> {
+    parse(text).1
+}
+
+/// Parses `text`, which must be a single function without errors.
+fn function(text: &str) -> FunctionDecl {
+    let (mut program, errors) = parse(text);
+    assert!(errors.is_empty(), "errors for {:?}: {:?}", text, errors);
+
+    match program.decls.pop() {
+        Some(Decl::Function(function)) if program.decls.is_empty() => function,
+        decl => panic!("expected a function for {:?}, found {:?}", text, decl),
+    }
+}
+
+#[test]
+fn signed_literals_only_reach_the_minimum_when_negated() {
+    assert!(errors("fn f() { let a = -128i8; let b = 127i8; let c = - -128i8; }").is_empty());
+
+    assert_eq!(
+        errors("fn f() { let a = 128i8; }"),
+        ["literal out of range for 'i8'"]
+    );
+    assert_eq!(
+        errors("fn f() { let a = -128i8.abs(); }"),
+        ["literal out of range for 'i8'"]
+    );
+}
+
+/// The titles of the warnings parsing `text` reports.
+fn warnings(text: &str) -> Vec<String> {
+    let mut sources = Sources::new();
+    let id = sources.add(Source::new("test.ath", text));
+
+    let (_, errors) = parse_program(&sources, id);
+    errors
+        .warnings()
+        .iter()
+        .map(|e| e.title().to_string())
+        .collect()
+}
+
+#[test]
+fn doc_comments_before_statements_are_unused() {
+    let text = "fn f() {\n    /// the answer\n    let a = 42;\n    a;\n}";
+
+    assert!(errors(text).is_empty());
+    assert_eq!(warnings(text), ["unused doc comment"]);
+}
+
+#[test]
+fn variants_take_docs_and_attributes() {
+    let (program, errors) = parse("enum E {\n    /// a\n    #[old]\n    A,\n    #[new] B(i32),\n}");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Enum(ref enumeration) = program.decls[0] else {
+        panic!("expected an enum, found {:?}", program.decls[0]);
+    };
+
+    let variants = &enumeration.variants;
+    assert_eq!(
+        (variants[0].docs.len(), variants[0].attributes.len()),
+        (1, 1)
+    );
+    assert_eq!(
+        (variants[1].docs.len(), variants[1].attributes.len()),
+        (0, 1)
+    );
+}
+
+#[test]
+fn crate_is_only_a_keyword_at_the_start_of_a_path() {
+    let (program, errors) = parse("use crate::a::{b, self};\nfn f() { let crate = 1; crate + 1; }");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Use(ref decl) = program.decls[0] else {
+        panic!("expected a use declaration, found {:?}", program.decls[0]);
+    };
+
+    let UseTree::Group { ref prefix, .. } = decl.tree else {
+        panic!("expected a group, found {:?}", decl.tree);
+    };
+
+    assert!(matches!(prefix.segments[0], PathSegment::CrateSegment(_)));
+    assert_eq!(prefix.to_string(), "crate::a");
+}
+
+#[test]
+fn blocks_end_with_an_optional_expression() {
+    assert_eq!(errors("fn f() { 1 2 }"), ["expected ';' after expression"]);
+
+    let (program, errors) =
+        parse("fn f(a: bool) -> i32 { let x = if a { 1 } else { 2 }; x }\nfn g() { loop {} }");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Function(ref f) = program.decls[0] else {
+        panic!("expected a function, found {:?}", program.decls[0]);
+    };
+
+    assert_eq!(f.body.stmts.len(), 1);
+    assert!(matches!(f.body.expr.as_deref(), Some(Expr::Path(_))));
+
+    let StmtKind::Let(ref stmt) = f.body.stmts[0].kind else {
+        panic!("expected a let statement, found {:?}", f.body.stmts[0]);
+    };
+
+    let Some(Expr::If(ref expr)) = stmt.expr else {
+        panic!("expected an if expression, found {:?}", stmt.expr);
+    };
+
+    assert!(matches!(
+        expr.then_block.expr.as_deref(),
+        Some(Expr::Literal(_))
+    ));
+
+    let Decl::Function(ref g) = program.decls[1] else {
+        panic!("expected a function, found {:?}", program.decls[1]);
+    };
+
+    assert!(g.body.stmts.is_empty());
+    assert!(matches!(g.body.expr.as_deref(), Some(Expr::Loop(_))));
+}
+
+#[test]
+fn declarations_in_blocks_are_skipped() {
+    let (program, errors) = parse("fn a() { const N: i32 = 4; let x = N; }\nfn b() {}");
+    assert_eq!(errors, ["declarations are not allowed in blocks"]);
+    assert_eq!(program.decls.len(), 2);
+
+    let Decl::Function(ref a) = program.decls[0] else {
+        panic!("expected a function, found {:?}", program.decls[0]);
+    };
+
+    assert!(matches!(
+        a.body.stmts[..],
+        [Stmt {
+            kind: StmtKind::Let(_),
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn blocks_are_unclosed_at_the_end_of_file() {
+    let (program, errors) = parse("fn a() {\n    let x = 1;\n\nfn b() {}");
+    assert_eq!(
+        errors,
+        ["declarations are not allowed in blocks", "unclosed block"]
+    );
+    assert_eq!(program.decls.len(), 1);
+}
+
+#[test]
+fn enums_take_attributes() {
+    let (program, errors) = parse("/// shapes\n#[repr(u8)]\n#[derive(Debug)]\nenum E { A }");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Enum(ref enumeration) = program.decls[0] else {
+        panic!("expected an enum, found {:?}", program.decls[0]);
+    };
+
+    let names: Vec<_> = (enumeration.attributes.iter())
+        .map(|attribute| attribute.name.as_str())
+        .collect();
+
+    assert_eq!(names, ["repr", "derive"]);
+    assert_eq!(enumeration.docs.len(), 1);
+}
+
+#[test]
+fn enums_take_a_visibility() {
+    let (program, errors) = parse("pub enum E { A }\nenum F { B }");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let visibilities: Vec<_> = (program.decls.iter())
+        .map(|decl| match decl {
+            Decl::Enum(enumeration) => enumeration.visibility.is_public(),
+            decl => panic!("expected an enum, found {:?}", decl),
+        })
+        .collect();
+
+    assert_eq!(visibilities, [true, false]);
+}
+
+#[test]
+fn pub_applies_to_declarations_fields_and_methods() {
+    let (program, errors) = parse(
+        "pub fn f() {}\nfn g() {}\npub mod m { pub class C { pub x: i32, y: i32, pub fn get(self) -> i32 { self.x } fn set(*self) {} } }",
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Function(ref f) = program.decls[0] else {
+        panic!("expected a function, found {:?}", program.decls[0]);
+    };
+
+    let Decl::Function(ref g) = program.decls[1] else {
+        panic!("expected a function, found {:?}", program.decls[1]);
+    };
+
+    assert!(f.visibility.is_public());
+    assert!(!g.visibility.is_public());
+
+    let Decl::Module(ref module) = program.decls[2] else {
+        panic!("expected a module, found {:?}", program.decls[2]);
+    };
+
+    assert!(module.visibility.is_public());
+
+    let Decl::Class(ref class) = module.decls[0] else {
+        panic!("expected a class, found {:?}", module.decls[0]);
+    };
+
+    assert!(class.visibility.is_public());
+
+    let fields: Vec<_> = (class.fields.iter())
+        .map(|field| field.visibility.is_public())
+        .collect();
+    let methods: Vec<_> = (class.methods.iter())
+        .map(|method| method.visibility.is_public())
+        .collect();
+
+    assert_eq!(fields, [true, false]);
+    assert_eq!(methods, [true, false]);
+}
+
+#[test]
+fn consts_and_statics_are_parsed() {
+    assert_eq!(errors("const N: i32;"), ["expected '=', found ';'"]);
+
+    let (program, errors) = parse("pub const N: i32 = 4 * 2;\nstatic NAME: u8 = 1;");
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Const(ref constant) = program.decls[0] else {
+        panic!("expected a const, found {:?}", program.decls[0]);
+    };
+
+    assert!(constant.visibility.is_public());
+    assert_eq!(constant.name.as_str(), "N");
+    assert_eq!(constant.ty, Type::Int(Int::i32()));
+    assert!(matches!(constant.value, Expr::Binary(_)));
+
+    let Decl::Static(ref variable) = program.decls[1] else {
+        panic!("expected a static, found {:?}", program.decls[1]);
+    };
+
+    assert!(!variable.visibility.is_public());
+    assert_eq!(variable.name.as_str(), "NAME");
+    assert_eq!(variable.ty, Type::Int(Int::u8()));
+}
+
+#[test]
+fn errors_list_what_was_expected() {
+    assert_eq!(
+        errors("fn f(a: ) {}"),
+        ["expected one of identifier, '::', 'self', 'super', '*', '[', 'fn', found ')'"]
+    );
+    assert_eq!(
+        errors("fn f() { foo(1, 2; }"),
+        ["expected one of '.', '(', '[', '+', '-', '*', '/', '%', '&', '|', '^', '<<', '>>', '==', '!=', '<', '<=', '>', '>=', '&&', '||', '=', ')', ',', found ';'"]
+    );
+    assert_eq!(errors("class C { x i32 }"), ["expected ':', found 'i32'"]);
+}
+
+#[test]
+fn invalid_tokens_are_only_reported_by_the_lexer() {
+    assert_eq!(errors("fn f(a: $) {}"), ["invalid character '$'"]);
+    assert_eq!(errors("fn f() { let x = $; }"), ["invalid character '$'"]);
+    assert_eq!(errors("$ fn f() {}"), ["invalid character '$'"]);
+}
+
+/// Writes `pattern` back as source, with or-patterns in parentheses.
+fn pattern(pattern: &Pattern) -> String {
+    let patterns = |patterns: &[Pattern]| {
+        let patterns: Vec<_> = patterns.iter().map(self::pattern).collect();
+        patterns.join(", ")
+    };
+
+    match pattern.kind {
+        PatternKind::Wildcard => String::from("_"),
+        PatternKind::Binding(ref name) => name.to_string(),
+        PatternKind::Literal(ref literal) => format!("{:?}", literal),
+        PatternKind::Path(ref path) => path.to_string(),
+        PatternKind::Tuple {
+            ref path,
+            ref fields,
+        } => format!("{}({})", path, patterns(fields)),
+        PatternKind::Struct {
+            ref path,
+            ref fields,
+            has_rest,
+        } => {
+            let mut fields: Vec<_> = (fields.iter())
+                .map(|field| format!("{}: {}", field.name, self::pattern(&field.pattern)))
+                .collect();
+
+            if has_rest {
+                fields.push(String::from(".."));
+            }
+
+            format!("{} {{ {} }}", path, fields.join(", "))
+        }
+        PatternKind::Or(ref alternatives) => {
+            let alternatives: Vec<_> = alternatives.iter().map(self::pattern).collect();
+            format!("({})", alternatives.join(" | "))
+        }
+    }
+}
+
+#[test]
+fn match_arms_take_patterns() {
+    let (program, errors) = parse(
+        "fn f(s: Shape) -> i32 {\n    match s {\n        Shape::Circle(0) | Shape::Empty => 1,\n        Shape::Rect { w, h: Point(x, _), .. } => w,\n        Shape::Line(Point(a, b), _) => { a }\n        Line { from: Point { x, y }, to } => x,\n        _ => 0,\n    }\n}",
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Function(ref f) = program.decls[0] else {
+        panic!("expected a function, found {:?}", program.decls[0]);
+    };
+
+    let Some(Expr::Match(ref expr)) = f.body.expr.as_deref() else {
+        panic!("expected a match expression, found {:?}", f.body.expr);
+    };
+
+    let patterns: Vec<_> = expr.arms.iter().map(|arm| pattern(&arm.pattern)).collect();
+
+    assert_eq!(
+        patterns,
+        [
+            "(Shape::Circle(Int { value: 0, suffix: None }) | Shape::Empty)",
+            "Shape::Rect { w: w, h: Point(x, _), .. }",
+            "Shape::Line(Point(a, b), _)",
+            "Line { from: Point { x: x, y: y }, to: to }",
+            "_",
+        ]
+    );
+
+    assert!(matches!(expr.arms[2].body, Expr::Block(_)));
+}
+
+#[test]
+fn variant_fields_have_no_defaults() {
+    assert_eq!(
+        errors("enum E { A { x: i32 = 5 } }"),
+        ["variant fields cannot have default values"]
+    );
+}
+
+#[test]
+fn functions_fill_in_their_declaration() {
+    let text = "fn name<T>(a: T, b: i32) -> T { a }";
+    let f = function(text);
+
+    assert_eq!(f.name.as_str(), "name");
+    assert_eq!(f.generics.to_string(), "<T>");
+    assert_eq!(f.return_type.to_string(), "T");
+    assert!(f.body.expr.is_some());
+    assert_eq!((f.span.index, f.span.length), (0, text.len()));
+
+    let arguments: Vec<_> = (f.arguments.iter())
+        .map(|argument| format!("{}: {}", argument.name, argument.ty))
+        .collect();
+
+    assert_eq!(arguments, ["a: T", "b: i32"]);
+
+    let g = function("fn g() {}");
+    assert_eq!(g.return_type, Type::Void);
+    assert!(g.generics.is_empty() && g.arguments.is_empty());
+}
+
+/// The text of `source` covered by `span`.
+fn text(source: &str, span: Span) -> &str {
+    &source[span.index..span.index + span.length]
+}
+
+#[test]
+fn statements_have_accurate_spans() {
+    let source = "fn f() { let x; let y: i32 = 1; return; return y; x }";
+    let f = function(source);
+
+    let stmts: Vec<_> = (f.body.stmts.iter())
+        .map(|stmt| {
+            let span = match stmt.kind {
+                StmtKind::Let(ref stmt) => stmt.span,
+                StmtKind::Return(ref stmt) => stmt.span,
+                StmtKind::Expr(ref expr) => expr.span(),
+            };
+
+            (text(source, stmt.span), text(source, span))
+        })
+        .collect();
+
+    assert_eq!(
+        stmts,
+        [
+            ("let x;", "let x"),
+            ("let y: i32 = 1;", "let y: i32 = 1"),
+            ("return;", "return"),
+            ("return y;", "return y"),
+        ]
+    );
+
+    let StmtKind::Let(ref x) = f.body.stmts[0].kind else {
+        panic!("expected a let statement, found {:?}", f.body.stmts[0]);
+    };
+
+    assert!(x.ty.is_none() && x.expr.is_none());
+
+    let StmtKind::Let(ref y) = f.body.stmts[1].kind else {
+        panic!("expected a let statement, found {:?}", f.body.stmts[1]);
+    };
+
+    assert_eq!(y.ty, Some(Type::Int(Int::i32())));
+    assert!(y.expr.is_some());
+
+    let StmtKind::Return(ref stmt) = f.body.stmts[2].kind else {
+        panic!("expected a return statement, found {:?}", f.body.stmts[2]);
+    };
+
+    assert!(stmt.expr.is_none());
+}
+
+#[test]
+fn statements_need_semicolons() {
+    assert_eq!(
+        errors("fn f() { let x = 1 let y = 2; }"),
+        ["expected ';' after let statement"]
+    );
+    assert_eq!(
+        errors("fn f() { return }"),
+        ["expected ';' after return statement"]
+    );
+    assert_eq!(
+        errors("fn f() { f() g(); }"),
+        ["expected ';' after expression"]
+    );
+}
+
+#[test]
+fn types_are_parsed() {
+    let f = function(
+        "fn f(a: [i32; 4], b: fn(i32, bool) -> f64, c: List<List<i32>>, d: *Name<T>, e: void, f: isize, g: f16, h: u128, i: fn()) {}",
+    );
+
+    let types: Vec<_> = (f.arguments.iter())
+        .map(|argument| argument.ty.to_string())
+        .collect();
+
+    assert_eq!(
+        types,
+        [
+            "[i32; 4]",
+            "(i32, bool) -> f64",
+            "List<List<i32>>",
+            "*Name<T>",
+            "void",
+            "isize",
+            "f16",
+            "u128",
+            "() -> void",
+        ]
+    );
+
+    assert!(matches!(f.arguments[2].ty, Type::Unresolved(_)));
+    assert_eq!(f.arguments[5].ty, Type::Int(Int::signed(None)));
+    assert_eq!(f.arguments[6].ty, Type::Float(Float::f16()));
+}
+
+/// Parses `text` as the trailing expression of a function body, without errors.
+fn expr(text: &str) -> Expr {
+    let f = function(&format!("fn f() {{ {} }}", text));
+
+    match f.body.expr {
+        Some(expr) if f.body.stmts.is_empty() => *expr,
+        _ => panic!(
+            "expected a single expression for {:?}, found {:?}",
+            text, f.body
+        ),
+    }
+}
+
+/// Writes `expr` back as source, with binary expressions in parentheses.
+fn show(expr: &Expr) -> String {
+    let list = |exprs: &[Expr]| {
+        let exprs: Vec<_> = exprs.iter().map(show).collect();
+        exprs.join(", ")
+    };
+
+    match expr {
+        Expr::Literal(literal) => match literal.kind {
+            LiteralKind::Int { value, .. } => value.to_string(),
+            LiteralKind::Float { value, .. } => value.to_string(),
+            LiteralKind::Bool(value) => value.to_string(),
+            ref kind => format!("{:?}", kind),
+        },
+        Expr::Paren(expr) => format!("({})", show(&expr.expr)),
+        Expr::Path(path) => path.to_string(),
+        Expr::Field(expr) => format!("{}.{}", show(&expr.class), expr.field),
+        Expr::Call(expr) => format!("{}({})", show(&expr.callee), list(&expr.arguments)),
+        Expr::MethodCall(expr) => {
+            let spec = match expr.spec.is_empty() {
+                true => String::new(),
+                false => format!("::{}", expr.spec),
+            };
+
+            let receiver = show(&expr.receiver);
+            let arguments = list(&expr.arguments);
+            format!("{}.{}{}({})", receiver, expr.method, spec, arguments)
+        }
+        Expr::Index(expr) => format!("{}[{}]", show(&expr.expr), show(&expr.index)),
+        Expr::Unary(expr) => {
+            let op = match expr.op {
+                UnaryOp::Neg => "-",
+                UnaryOp::Not => "!",
+                UnaryOp::Ref => "&",
+                UnaryOp::Deref => "*",
+            };
+
+            format!("{}{}", op, show(&expr.expr))
+        }
+        Expr::Binary(expr) => {
+            let op = match expr.op {
+                BinOp::Add => "+",
+                BinOp::Sub => "-",
+                BinOp::Mul => "*",
+                BinOp::Div => "/",
+                BinOp::Mod => "%",
+                BinOp::BitAnd => "&",
+                BinOp::BitOr => "|",
+                BinOp::BitXor => "^",
+                BinOp::Shl => "<<",
+                BinOp::Shr => ">>",
+                BinOp::Eq => "==",
+                BinOp::Ne => "!=",
+                BinOp::Lt => "<",
+                BinOp::Le => "<=",
+                BinOp::Gt => ">",
+                BinOp::Ge => ">=",
+                BinOp::And => "&&",
+                BinOp::Or => "||",
+            };
+
+            format!("({} {} {})", show(&expr.lhs), op, show(&expr.rhs))
+        }
+        expr => panic!("can't show {:?}", expr),
+    }
+}
+
+#[test]
+fn paths_are_expressions() {
+    assert_eq!(show(&expr("foo")), "foo");
+    assert_eq!(show(&expr("a::b::c")), "a::b::c");
+    assert_eq!(show(&expr("self.x")), "self.x");
+    assert_eq!(show(&expr("super::x")), "super::x");
+    assert_eq!(show(&expr("make::<i32, f64>(1)")), "make<i32, f64>(1)");
+
+    let source = "fn f() { ::root::item }";
+    let Expr::Path(path) = expr("::root::item") else {
+        panic!("expected a path");
+    };
+
+    assert!(path.is_absolute);
+    assert_eq!(text(source, path.span), "::root::item");
+
+    let segments: Vec<_> = (path.segments.iter())
+        .map(|segment| match segment {
+            PathSegment::IdentSegment(segment) => text(source, segment.span),
+            segment => panic!("expected an identifier, found {:?}", segment),
+        })
+        .collect();
+
+    assert_eq!(segments, ["root", "item"]);
+
+    let Expr::Call(call) = expr("make::<i32, f64>()") else {
+        panic!("expected a call");
+    };
+
+    let Expr::Path(ref path) = *call.callee else {
+        panic!("expected a path, found {:?}", call.callee);
+    };
+
+    let PathSegment::IdentSegment(ref segment) = path.segments[0] else {
+        panic!("expected an identifier, found {:?}", path.segments[0]);
+    };
+
+    assert_eq!(segment.spec.generics, [Type::i32(), Type::f64()]);
+}
+
+#[test]
+fn literals_keep_their_suffix_and_span() {
+    let literals = [
+        (
+            "1",
+            LiteralKind::Int {
+                value: 1,
+                suffix: None,
+            },
+        ),
+        (
+            "0xffu8",
+            LiteralKind::Int {
+                value: 255,
+                suffix: Some(Int::u8()),
+            },
+        ),
+        (
+            "2.5",
+            LiteralKind::Float {
+                value: 2.5,
+                suffix: None,
+            },
+        ),
+        (
+            "1e3f32",
+            LiteralKind::Float {
+                value: 1000.0,
+                suffix: Some(Float::f32()),
+            },
+        ),
+        ("true", LiteralKind::Bool(true)),
+        ("false", LiteralKind::Bool(false)),
+        ("'c'", LiteralKind::Char('c')),
+        (
+            "b'a'",
+            LiteralKind::Int {
+                value: 97,
+                suffix: Some(Int::u8()),
+            },
+        ),
+        ("\"s\\n\"", LiteralKind::String(String::from("s\n"))),
+        ("b\"ab\"", LiteralKind::ByteString(b"ab".to_vec())),
+    ];
+
+    for (literal, kind) in literals {
+        let source = format!("fn f() {{ {} }}", literal);
+
+        let Expr::Literal(expr) = expr(literal) else {
+            panic!("expected a literal for {:?}", literal);
+        };
+
+        assert_eq!(expr.kind, kind);
+        assert_eq!(text(&source, expr.span), literal);
+    }
+}
+
+#[test]
+fn binary_operators_are_left_associative() {
+    assert_eq!(show(&expr("a - b - c")), "((a - b) - c)");
+    assert_eq!(show(&expr("a / b * c % d")), "(((a / b) * c) % d)");
+    assert_eq!(show(&expr("a - b * c + d")), "((a - (b * c)) + d)");
+    assert_eq!(show(&expr("a && b && c")), "((a && b) && c)");
+}
+
+#[test]
+fn binary_operators_bind_by_precedence() {
+    assert_eq!(
+        show(&expr("a || b && c | d ^ e & f << 1 == g")),
+        "(a || (b && ((c | (d ^ (e & (f << 1)))) == g)))"
+    );
+    assert_eq!(
+        show(&expr("a < b == c >= d != e")),
+        "(((a < b) == (c >= d)) != e)"
+    );
+    assert_eq!(show(&expr("-a * !b + *c")), "((-a * !b) + *c)");
+    // the parentheses of the source are kept around the parenthesized binary expression
+    assert_eq!(show(&expr("(a + b) * c")), "(((a + b)) * c)");
+    assert_eq!(show(&expr("a >> 2 >> b")), "((a >> 2) >> b)");
+}
+
+#[test]
+fn postfix_operators_chain() {
+    assert_eq!(show(&expr("a.b.c")), "a.b.c");
+    assert_eq!(show(&expr("f(x)(y)")), "f(x)(y)");
+    assert_eq!(show(&expr("m[i][j]")), "m[i][j]");
+    assert_eq!(show(&expr("obj.items[0].len()")), "obj.items[0].len()");
+    assert_eq!(show(&expr("-a.b(c)[d]")), "-a.b(c)[d]");
+    assert_eq!(show(&expr("f()")), "f()");
+    assert_eq!(show(&expr("f(a, b,)")), "f(a, b)");
+
+    let Expr::Field(field) = expr("a.b.c") else {
+        panic!("expected a field expression");
+    };
+
+    assert_eq!(show(&field.class), "a.b");
+}
+
+#[test]
+fn method_calls_take_a_turbofish() {
+    let source = "fn f() { list.get::<i32>(0).unwrap() }";
+
+    let Expr::MethodCall(unwrap) = expr("list.get::<i32>(0).unwrap()") else {
+        panic!("expected a method call");
+    };
+
+    assert_eq!(unwrap.method.as_str(), "unwrap");
+    assert!(unwrap.spec.is_empty() && unwrap.arguments.is_empty());
+    assert_eq!(text(source, unwrap.span), "list.get::<i32>(0).unwrap()");
+
+    let Expr::MethodCall(ref get) = *unwrap.receiver else {
+        panic!("expected a method call, found {:?}", unwrap.receiver);
+    };
+
+    assert_eq!(get.method.as_str(), "get");
+    assert_eq!(get.spec.generics, [Type::i32()]);
+    assert_eq!(show(&get.receiver), "list");
+    assert_eq!(text(source, get.span), "list.get::<i32>(0)");
+
+    // a field followed by a call is a method call, a parenthesized field is not
+    assert!(matches!(expr("a.b()"), Expr::MethodCall(_)));
+    assert!(matches!(expr("(a.b)()"), Expr::Call(_)));
+}
+
+#[test]
+fn classes_have_fields_and_methods() {
+    let (program, errors) = parse(
+        "class Name<T> {\n    x: i32 = 0,\n    next: *Name<T>,\n    fn get(self) -> i32 { self.x },\n    fn set(*self, v: i32) { self.x = v; }\n    fn new() -> Name<T> { Name::make() },\n}",
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let Decl::Class(ref class) = program.decls[0] else {
+        panic!("expected a class, found {:?}", program.decls[0]);
+    };
+
+    assert_eq!(class.name.as_str(), "Name");
+    assert_eq!(class.generics.to_string(), "<T>");
+
+    let fields: Vec<_> = (class.fields.iter())
+        .map(|field| {
+            let default = field.default.as_ref().map(show);
+            (field.name.to_string(), field.ty.to_string(), default)
+        })
+        .collect();
+
+    assert_eq!(
+        fields,
+        [
+            (
+                String::from("x"),
+                String::from("i32"),
+                Some(String::from("0"))
+            ),
+            (String::from("next"), String::from("*Name<T>"), None),
+        ]
+    );
+
+    let methods: Vec<_> = (class.methods.iter())
+        .map(|method| {
+            let self_argument = (method.self_argument.as_ref()).map(|argument| argument.is_pointer);
+            (method.name.as_str(), self_argument, method.arguments.len())
+        })
+        .collect();
+
+    assert_eq!(
+        methods,
+        [
+            ("get", Some(false), 0),
+            ("set", Some(true), 1),
+            ("new", None, 0)
+        ]
+    );
+
+    assert!(class.methods[2].is_static());
+    assert_eq!(class.methods[2].return_ty.to_string(), "Name<T>");
+    assert!(class.methods[0].body.expr.is_some());
+}
+
+/// The names of `attributes`, with `!` before inner attributes.
+fn attribute_names(attributes: &[Attribute]) -> Vec<String> {
+    (attributes.iter())
+        .map(|attribute| match attribute.style {
+            AttributeStyle::Outer => attribute.name.to_string(),
+            AttributeStyle::Inner => format!("!{}", attribute.name),
+        })
+        .collect()
+}
+
+#[test]
+fn attributes_apply_to_the_following_item() {
+    let (program, errors) = parse(
+        "#![no_std]\n#[inline]\nfn f() {\n    #[allow(unused)]\n    let x = 1;\n}\n#[repr(C)]\nclass C {\n    #[skip] x: i32,\n    #[test] fn m() {}\n}\n#[cfg(test)]\nmod m {\n    #![doc(hidden)]\n    fn g() {}\n}",
+    );
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    assert_eq!(attribute_names(&program.attributes), ["!no_std"]);
+
+    let Decl::Function(ref f) = program.decls[0] else {
+        panic!("expected a function, found {:?}", program.decls[0]);
+    };
+
+    assert_eq!(attribute_names(&f.attributes), ["inline"]);
+    assert_eq!(attribute_names(&f.body.stmts[0].attributes), ["allow"]);
+
+    let Decl::Class(ref class) = program.decls[1] else {
+        panic!("expected a class, found {:?}", program.decls[1]);
+    };
+
+    assert_eq!(attribute_names(&class.attributes), ["repr"]);
+    assert_eq!(attribute_names(&class.fields[0].attributes), ["skip"]);
+    assert_eq!(attribute_names(&class.methods[0].attributes), ["test"]);
+
+    let Decl::Module(ref module) = program.decls[2] else {
+        panic!("expected a module, found {:?}", program.decls[2]);
+    };
+
+    assert_eq!(attribute_names(&module.attributes), ["cfg", "!doc"]);
+}
+
+#[test]
+fn attributes_take_values_and_key_values() {
+    let f = function("#[deprecated(old, note = \"use g\", since = 2)]\nfn f() {}");
+    let attribute = &f.attributes[0];
+
+    let args: Vec<_> = (attribute.args.iter())
+        .map(|arg| match arg {
+            AttributeArg::Value(AttributeValue::Ident(ident)) => ident.to_string(),
+            AttributeArg::KeyValue { key, value, .. } => match value {
+                AttributeValue::Literal(literal) => format!("{} = {:?}", key, literal.kind),
+                AttributeValue::Ident(ident) => format!("{} = {}", key, ident),
+            },
+            arg => panic!("unexpected argument {:?}", arg),
+        })
+        .collect();
+
+    assert_eq!(
+        args,
+        [
+            "old",
+            "note = String(\"use g\")",
+            "since = Int { value: 2, suffix: None }"
+        ]
+    );
+}
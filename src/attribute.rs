@@ -0,0 +1,54 @@
+use crate::{expr::LiteralExpr, ident::Ident, span::Span};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeStyle {
+    /// `#[...]`, applying to the item that follows.
+    Outer,
+    /// `#![...]`, applying to the enclosing module.
+    Inner,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    Ident(Ident),
+    Literal(LiteralExpr),
+}
+
+impl AttributeValue {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Ident(ident) => ident.span(),
+            Self::Literal(literal) => literal.span,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeArg {
+    /// `arg`, e.g. `C` in `#[repr(C)]`.
+    Value(AttributeValue),
+    /// `key = value`, e.g. `#[deprecated(note = "use bar")]`.
+    KeyValue {
+        key: Ident,
+        value: AttributeValue,
+        span: Span,
+    },
+}
+
+/// An attribute, `#[name]` or `#[name(arg, key = value)]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub style: AttributeStyle,
+    pub name: Ident,
+    pub args: Vec<AttributeArg>,
+    pub span: Span,
+}
+
+impl Attribute {
+    /// Finds the first attribute named `name`.
+    pub fn find<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+        attributes
+            .iter()
+            .find(|attribute| attribute.name.as_str() == name)
+    }
+}
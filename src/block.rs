@@ -1,8 +1,10 @@
-use crate::{span::Span, stmt::Stmt};
+use crate::{expr::Expr, span::Span, stmt::Stmt};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Block {
     pub stmts: Vec<Stmt>,
+    /// The final expression without a `;`, the value of the block.
+    pub expr: Option<Box<Expr>>,
     pub span: Span,
 }
 
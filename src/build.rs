@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+
+use crate::{
+    data::{ClassId, ConstId, Data, EnumId, FunctionId, ModuleId, StaticId},
+    decl::{Decl, UseTree},
+    error::{Error, Errors},
+    function::Function,
+    ident::Ident,
+    module::{Entry, Module},
+    path::{Path, PathSegment},
+    program::Program,
+    span::Span,
+    visibility::Visibility,
+};
+
+/// What a name in a module refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Item {
+    Module(ModuleId),
+    Class(ClassId),
+    Enum(EnumId),
+    Function(FunctionId),
+    Const(ConstId),
+    Static(StaticId),
+}
+
+/// Why a path doesn't resolve.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResolveError {
+    /// A name in the path doesn't exist, at least not until more names are imported.
+    Unresolved,
+    /// A name in the path is private, and the path is outside of the module declaring it.
+    Private(Ident),
+}
+
+/// A single path imported by a `use` declaration.
+#[derive(Clone, Debug)]
+struct Import {
+    module: ModuleId,
+    path: Path,
+    alias: Option<Ident>,
+    is_glob: bool,
+}
+
+/// Splits `tree` into the paths it imports.
+fn flatten(module: ModuleId, prefix: &Path, tree: &UseTree, imports: &mut Vec<Import>) {
+    let join = |path: &Path| Path {
+        is_absolute: prefix.is_absolute || path.is_absolute,
+        segments: [prefix.segments.as_slice(), path.segments.as_slice()].concat(),
+        span: path.span,
+    };
+
+    match tree {
+        UseTree::Path { path, alias } => imports.push(Import {
+            module,
+            path: join(path),
+            alias: alias.clone(),
+            is_glob: false,
+        }),
+        UseTree::Glob { prefix: glob } => imports.push(Import {
+            module,
+            path: join(glob),
+            alias: None,
+            is_glob: true,
+        }),
+        UseTree::Group {
+            prefix: group,
+            trees,
+        } => {
+            let prefix = join(group);
+
+            for tree in trees {
+                flatten(module, &prefix, tree, imports);
+            }
+        }
+    }
+}
+
+/// Defines `name` in `map`, shadowing a name imported by a glob, reporting names
+/// defined more than once.
+fn define<T>(
+    map: &mut HashMap<Ident, Entry<T>>,
+    name: &Ident,
+    id: T,
+    visibility: Visibility,
+    errors: &mut Errors,
+) {
+    if map.get(name).is_some_and(|entry| !entry.is_glob) {
+        let error =
+            Error::new(format!("'{}' is defined multiple times", name)).with_span(name.span());
+
+        errors.push(error);
+        return;
+    }
+
+    map.insert(name.clone(), Entry::new(id, visibility));
+}
+
+/// Imports the entries of `from` missing in `into`, only the public ones unless
+/// `is_private_visible`, returning whether any were added.
+fn merge<T: Copy>(
+    into: &mut HashMap<Ident, Entry<T>>,
+    from: &HashMap<Ident, Entry<T>>,
+    is_private_visible: bool,
+) -> bool {
+    let mut changed = false;
+
+    for (name, entry) in from {
+        let is_visible = is_private_visible || entry.visibility.is_public();
+
+        if is_visible && !into.contains_key(name) {
+            into.insert(name.clone(), Entry::glob(entry.id));
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+impl Data {
+    /// Adds the declarations of `program` as a new root module named `name`, and
+    /// resolves its `use` declarations.
+    pub fn add_program(&mut self, program: &Program, name: Ident, errors: &mut Errors) -> ModuleId {
+        let module = Module::new(
+            Visibility::Public(Span::null()),
+            name,
+            None,
+            program.docs.clone(),
+            program.attributes.clone(),
+            Span::null(),
+        );
+        let root = self.modules.push(module);
+
+        let mut imports = Vec::new();
+        self.add_decls(root, &program.decls, &mut imports, errors);
+        self.resolve_imports(&imports, errors);
+
+        root
+    }
+
+    fn add_decls(
+        &mut self,
+        module: ModuleId,
+        decls: &[Decl],
+        imports: &mut Vec<Import>,
+        errors: &mut Errors,
+    ) {
+        for decl in decls {
+            match decl {
+                Decl::Function(decl) => {
+                    let function = self.functions.push(Function {
+                        name: decl.name.clone(),
+                        generics: decl.generics.clone(),
+                        arguments: decl.arguments.clone(),
+                        return_ty: decl.return_type.clone(),
+                    });
+
+                    let functions = &mut self.modules[module].functions;
+                    define(functions, &decl.name, function, decl.visibility, errors);
+                }
+                Decl::Class(decl) => {
+                    let class = self.classes.push(decl.clone());
+
+                    let classes = &mut self.modules[module].classes;
+                    define(classes, &decl.name, class, decl.visibility, errors);
+                }
+                Decl::Enum(decl) => {
+                    let enumeration = self.enums.push(decl.clone());
+
+                    let enums = &mut self.modules[module].enums;
+                    define(enums, &decl.name, enumeration, decl.visibility, errors);
+                }
+                Decl::Module(decl) => {
+                    let child = Module::new(
+                        decl.visibility,
+                        decl.name.clone(),
+                        Some(module),
+                        decl.docs.clone(),
+                        decl.attributes.clone(),
+                        decl.span,
+                    );
+                    let child = self.modules.push(child);
+
+                    let modules = &mut self.modules[module].modules;
+                    define(modules, &decl.name, child, decl.visibility, errors);
+
+                    self.add_decls(child, &decl.decls, imports, errors);
+                }
+                Decl::Use(decl) => {
+                    let prefix = Path {
+                        is_absolute: false,
+                        segments: Vec::new(),
+                        span: decl.span,
+                    };
+
+                    flatten(module, &prefix, &decl.tree, imports);
+                }
+                Decl::Const(decl) => {
+                    let constant = self.consts.push(decl.clone());
+
+                    let consts = &mut self.modules[module].consts;
+                    define(consts, &decl.name, constant, decl.visibility, errors);
+                }
+                Decl::Static(decl) => {
+                    let variable = self.statics.push(decl.clone());
+
+                    let statics = &mut self.modules[module].statics;
+                    define(statics, &decl.name, variable, decl.visibility, errors);
+                }
+                Decl::Error(_) => {}
+            }
+        }
+    }
+
+    /// The root module containing `module`.
+    pub fn root(&self, mut module: ModuleId) -> ModuleId {
+        while let Some(parent) = self.modules[module].parent {
+            module = parent;
+        }
+
+        module
+    }
+
+    /// Resolves the module `segments` refer to, starting from `module`, or from the
+    /// root module if `is_absolute`. Every module in the path must be visible to
+    /// `module`.
+    pub fn resolve_module(
+        &self,
+        module: ModuleId,
+        is_absolute: bool,
+        segments: &[PathSegment],
+    ) -> Result<ModuleId, ResolveError> {
+        let from = module;
+        let mut module = match is_absolute {
+            true => self.root(module),
+            false => module,
+        };
+
+        for segment in segments {
+            module = match segment {
+                PathSegment::IdentSegment(segment) => {
+                    let modules = &self.modules[module].modules;
+                    let entry = modules.get(&segment.name).ok_or(ResolveError::Unresolved)?;
+
+                    if !self.is_visible(from, module, entry.visibility) {
+                        return Err(ResolveError::Private(segment.name.clone()));
+                    }
+
+                    entry.id
+                }
+                PathSegment::SelfSegment(_) => module,
+                PathSegment::SuperSegment(_) => self.modules[module]
+                    .parent
+                    .ok_or(ResolveError::Unresolved)?,
+                PathSegment::CrateSegment(_) => self.root(module),
+            };
+        }
+
+        Ok(module)
+    }
+
+    /// The items named `name` in `module`, at most one of each kind.
+    pub fn lookup(&self, module: ModuleId, name: &Ident) -> Vec<Entry<Item>> {
+        let module = &self.modules[module];
+        let mut items = Vec::new();
+
+        if let Some(entry) = module.modules.get(name) {
+            items.push(Entry::new(Item::Module(entry.id), entry.visibility));
+        }
+
+        if let Some(entry) = module.classes.get(name) {
+            items.push(Entry::new(Item::Class(entry.id), entry.visibility));
+        }
+
+        if let Some(entry) = module.enums.get(name) {
+            items.push(Entry::new(Item::Enum(entry.id), entry.visibility));
+        }
+
+        if let Some(entry) = module.functions.get(name) {
+            items.push(Entry::new(Item::Function(entry.id), entry.visibility));
+        }
+
+        if let Some(entry) = module.consts.get(name) {
+            items.push(Entry::new(Item::Const(entry.id), entry.visibility));
+        }
+
+        if let Some(entry) = module.statics.get(name) {
+            items.push(Entry::new(Item::Static(entry.id), entry.visibility));
+        }
+
+        items
+    }
+
+    /// Resolves the items imported by `import` with their names, only the items
+    /// visible to the importing module.
+    fn resolve_import(&self, import: &Import) -> Result<Vec<(Ident, Item)>, ResolveError> {
+        let path = &import.path;
+        let (last, prefix) = path.segments.split_last().ok_or(ResolveError::Unresolved)?;
+
+        let PathSegment::IdentSegment(segment) = last else {
+            // `a::b::{self}`, `self`, `super` or `crate` imports a module
+            let module = self.resolve_module(import.module, path.is_absolute, &path.segments)?;
+
+            let name = import.alias.clone();
+            let name = name.unwrap_or_else(|| self.modules[module].name.clone());
+
+            return Ok(vec![(name, Item::Module(module))]);
+        };
+
+        let module = self.resolve_module(import.module, path.is_absolute, prefix)?;
+        let entries = self.lookup(module, &segment.name);
+
+        if entries.is_empty() {
+            return Err(ResolveError::Unresolved);
+        }
+
+        let visible: Vec<_> = (entries.into_iter())
+            .filter(|entry| self.is_visible(import.module, module, entry.visibility))
+            .collect();
+
+        if visible.is_empty() {
+            return Err(ResolveError::Private(segment.name.clone()));
+        }
+
+        let name = import.alias.clone().unwrap_or_else(|| segment.name.clone());
+        Ok(visible
+            .into_iter()
+            .map(|entry| (name.clone(), entry.id))
+            .collect())
+    }
+
+    /// Whether `module` is `ancestor` or one of its descendants.
+    pub fn is_within(&self, mut module: ModuleId, ancestor: ModuleId) -> bool {
+        loop {
+            if module == ancestor {
+                return true;
+            }
+
+            match self.modules[module].parent {
+                Some(parent) => module = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Whether a name with `visibility` in `owner` is visible to `module`.
+    pub fn is_visible(&self, module: ModuleId, owner: ModuleId, visibility: Visibility) -> bool {
+        visibility.is_public() || self.is_within(module, owner)
+    }
+
+    /// Adds the names of `target` visible to `module` to `module`, returning whether
+    /// any were added, names already in `module` take precedence.
+    ///
+    /// Private names, including the names `target` imports, are only visible inside
+    /// of `target`.
+    fn glob_import(&mut self, module: ModuleId, target: ModuleId) -> bool {
+        if module == target {
+            return false;
+        }
+
+        let is_private_visible = self.is_within(module, target);
+
+        let target = self.modules[target].clone();
+        let module = &mut self.modules[module];
+
+        let modules = merge(&mut module.modules, &target.modules, is_private_visible);
+        let classes = merge(&mut module.classes, &target.classes, is_private_visible);
+        let enums = merge(&mut module.enums, &target.enums, is_private_visible);
+        let functions = merge(&mut module.functions, &target.functions, is_private_visible);
+        let consts = merge(&mut module.consts, &target.consts, is_private_visible);
+        let statics = merge(&mut module.statics, &target.statics, is_private_visible);
+
+        modules || classes || enums || functions || consts || statics
+    }
+
+    /// Resolves `imports` until no more names are added, since imports may depend on
+    /// each other.
+    fn resolve_imports(&mut self, imports: &[Import], errors: &mut Errors) {
+        let mut resolved = vec![false; imports.len()];
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for (import, resolved) in imports.iter().zip(resolved.iter_mut()) {
+                if import.is_glob {
+                    let path = &import.path;
+                    let target =
+                        self.resolve_module(import.module, path.is_absolute, &path.segments);
+
+                    if let Ok(target) = target {
+                        *resolved = true;
+                        changed |= self.glob_import(import.module, target);
+                    }
+
+                    continue;
+                }
+
+                if *resolved {
+                    continue;
+                }
+
+                let Ok(items) = self.resolve_import(import) else {
+                    continue;
+                };
+
+                // imports are only visible in the importing module and its children
+                let private = Visibility::Private;
+
+                for (name, item) in items {
+                    let module = &mut self.modules[import.module];
+
+                    match item {
+                        Item::Module(id) => define(&mut module.modules, &name, id, private, errors),
+                        Item::Class(id) => define(&mut module.classes, &name, id, private, errors),
+                        Item::Enum(id) => define(&mut module.enums, &name, id, private, errors),
+                        Item::Function(id) => {
+                            define(&mut module.functions, &name, id, private, errors)
+                        }
+                        Item::Const(id) => define(&mut module.consts, &name, id, private, errors),
+                        Item::Static(id) => define(&mut module.statics, &name, id, private, errors),
+                    }
+                }
+
+                *resolved = true;
+                changed = true;
+            }
+        }
+
+        for (import, resolved) in imports.iter().zip(resolved) {
+            if resolved {
+                continue;
+            }
+
+            let path = &import.path;
+            let result = match import.is_glob {
+                true => self
+                    .resolve_module(import.module, path.is_absolute, &path.segments)
+                    .map(|_| ()),
+                false => self.resolve_import(import).map(|_| ()),
+            };
+
+            let error = match result {
+                Err(ResolveError::Private(name)) => {
+                    Error::new(format!("'{}' is private", name)).with_span(name.span())
+                }
+                _ => Error::new(format!("unresolved import '{}'", path)).with_span(path.span),
+            };
+
+            errors.push(error);
+        }
+    }
+}
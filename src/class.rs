@@ -1,9 +1,16 @@
-use crate::{field::Field, generics::Generics, ident::Ident, method::Method};
+use crate::{
+    attribute::Attribute, doc::Doc, field::Field, generics::Generics, ident::Ident, method::Method,
+    span::Span, visibility::Visibility,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Class {
+    pub visibility: Visibility,
     pub name: Ident,
     pub generics: Generics,
     pub fields: Vec<Field>,
     pub methods: Vec<Method>,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
 }
@@ -2,7 +2,13 @@ use std::ops::{Index, IndexMut};
 
 use deref_derive::{Deref, DerefMut};
 
-use crate::{class::Class, function::Function, module::Module};
+use crate::{
+    class::Class,
+    decl::{ConstDecl, StaticDecl},
+    enumeration::Enum,
+    function::Function,
+    module::Module,
+};
 
 macro_rules! vec_map {
     ($id:ident, $map:ident<$ty:ty>) => {
@@ -61,10 +67,16 @@ macro_rules! vec_map {
 vec_map!(ModuleId, Modules<Module>);
 vec_map!(FunctionId, GenericFunctions<Function>);
 vec_map!(ClassId, Classes<Class>);
+vec_map!(EnumId, Enums<Enum>);
+vec_map!(ConstId, Consts<ConstDecl>);
+vec_map!(StaticId, Statics<StaticDecl>);
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Data {
     pub modules: Modules,
     pub classes: Classes,
+    pub enums: Enums,
     pub functions: GenericFunctions,
+    pub consts: Consts,
+    pub statics: Statics,
 }
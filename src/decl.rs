@@ -1,18 +1,86 @@
 use crate::{
-    block::Block, function::Argument, generics::Generics, ident::Ident, span::Span, ty::Type,
+    attribute::Attribute, block::Block, class::Class, doc::Doc, enumeration::Enum, expr::Expr,
+    function::Argument, generics::Generics, ident::Ident, path::Path, span::Span, ty::Type,
+    visibility::Visibility,
 };
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FunctionDecl {
+    pub visibility: Visibility,
     pub name: Ident,
     pub generics: Generics,
     pub arguments: Vec<Argument>,
     pub return_type: Type,
     pub body: Block,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+/// An inline module, `mod name { ... }`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleDecl {
+    pub visibility: Visibility,
+    pub name: Ident,
+    pub decls: Vec<Decl>,
+    /// Both the outer `///` and the inner `//!` doc comments.
+    pub docs: Vec<Doc>,
+    /// Both the outer attributes and the inner `#![...]` attributes.
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+/// What a `use` declaration imports.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UseTree {
+    /// `a::b`, or `a::b as c`.
+    Path { path: Path, alias: Option<Ident> },
+    /// `a::b::*`.
+    Glob { prefix: Path },
+    /// `a::b::{c, d}`, the prefix may be empty.
+    Group { prefix: Path, trees: Vec<UseTree> },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseDecl {
+    pub tree: UseTree,
+    pub span: Span,
+}
+
+/// A constant, `const NAME: T = value;`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstDecl {
+    pub visibility: Visibility,
+    pub name: Ident,
+    pub ty: Type,
+    pub value: Expr,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+/// A static variable, `static NAME: T = value;`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaticDecl {
+    pub visibility: Visibility,
+    pub name: Ident,
+    pub ty: Type,
+    pub value: Expr,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
     pub span: Span,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Decl {
     Function(FunctionDecl),
+    Class(Class),
+    Enum(Enum),
+    Module(ModuleDecl),
+    Use(UseDecl),
+    Const(ConstDecl),
+    Static(StaticDecl),
+    /// Placeholder for a declaration that failed to parse, the error has already been
+    /// reported.
+    Error(Span),
 }
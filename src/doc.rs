@@ -0,0 +1,8 @@
+use crate::span::Span;
+
+/// A single line of documentation, from either a `///` or a `//!` comment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Doc {
+    pub text: String,
+    pub span: Span,
+}
@@ -0,0 +1,40 @@
+use crate::{
+    attribute::Attribute, doc::Doc, field::Field, generics::Generics, ident::Ident, span::Span,
+    ty::Type, visibility::Visibility,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantKind {
+    /// `Empty`.
+    Unit,
+    /// `Circle(f32)`.
+    Tuple(Vec<Type>),
+    /// `Rect { w: f32, h: f32 }`.
+    Struct(Vec<Field>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Variant {
+    pub name: Ident,
+    pub kind: VariantKind,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Enum {
+    pub visibility: Visibility,
+    pub name: Ident,
+    pub generics: Generics,
+    pub variants: Vec<Variant>,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+impl Enum {
+    pub fn variant(&self, name: &Ident) -> Option<&Variant> {
+        self.variants.iter().find(|variant| variant.name == *name)
+    }
+}
@@ -19,6 +19,32 @@ impl Errors {
         self.errors.is_empty()
     }
 
+    /// The number of errors, not counting warnings.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
+
+    pub fn push(&mut self, error: Error) {
+        self.errors.push(error);
+    }
+
+    pub fn push_warning(&mut self, warning: Error) {
+        self.warnings.push(warning);
+    }
+
+    pub fn extend(&mut self, other: Errors) {
+        self.errors.extend(other.errors);
+        self.warnings.extend(other.warnings);
+    }
+
     pub fn err(&mut self, title: impl Into<String>) -> &mut Error {
         self.errors.push(Error::new(title));
         self.errors.last_mut().unwrap()
@@ -70,6 +96,10 @@ impl Error {
         &self.title
     }
 
+    pub fn notes(&self) -> &[ErrorNote] {
+        &self.notes
+    }
+
     pub fn span(&self) -> Span {
         self.span
     }
@@ -101,11 +131,36 @@ impl<'a> ErrorFormatter<'a> {
     }
 
     pub fn format_error(&mut self, error: &Error) -> io::Result<()> {
+        self.format("error", color::Red, error)
+    }
+
+    pub fn format_warning(&mut self, warning: &Error) -> io::Result<()> {
+        self.format("warning", color::Yellow, warning)
+    }
+
+    pub fn format_errors(&mut self, errors: &Errors) -> io::Result<()> {
+        for warning in errors.warnings() {
+            self.format_warning(warning)?;
+        }
+
+        for error in errors.errors() {
+            self.format_error(error)?;
+        }
+
+        Ok(())
+    }
+
+    fn format(
+        &mut self,
+        level: &str,
+        level_color: impl color::Color,
+        error: &Error,
+    ) -> io::Result<()> {
         writeln!(
             self,
-            "{bold}{red}error{color_reset}: {}{style_reset}",
+            "{bold}{level_color}{level}{color_reset}: {}{style_reset}",
             error.title(),
-            red = color::Fg(color::Red),
+            level_color = color::Fg(level_color),
             color_reset = color::Fg(color::Reset),
             bold = style::Bold,
             style_reset = style::Reset,
@@ -144,6 +199,18 @@ impl<'a> ErrorFormatter<'a> {
             }
         }
 
+        for note in &error.notes {
+            writeln!(
+                self,
+                "{blue}     = {color_reset}{bold}note{style_reset}: {}",
+                note.note(),
+                blue = color::Fg(color::Blue),
+                color_reset = color::Fg(color::Reset),
+                bold = style::Bold,
+                style_reset = style::Reset,
+            )?;
+        }
+
         Ok(())
     }
 }
@@ -1,4 +1,23 @@
-use crate::{ident::Ident, path::Path, span::Span};
+use crate::{
+    block::Block, float::Float, ident::Ident, int::Int, path::Path, pattern::Pattern, span::Span,
+    specialization::Spec,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiteralKind {
+    Int { value: u128, suffix: Option<Int> },
+    Float { value: f64, suffix: Option<Float> },
+    Bool(bool),
+    Char(char),
+    String(String),
+    ByteString(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiteralExpr {
+    pub kind: LiteralKind,
+    pub span: Span,
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParenExpr {
@@ -27,6 +46,16 @@ pub struct CallExpr {
     pub span: Span,
 }
 
+/// A call of a method on `receiver`, e.g. `list.get::<i32>(0)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodCallExpr {
+    pub receiver: Box<Expr>,
+    pub method: Ident,
+    pub spec: Spec,
+    pub arguments: Vec<Expr>,
+    pub span: Span,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum UnaryOp {
     Neg,
@@ -49,25 +78,37 @@ pub enum BinOp {
     Mul,
     Div,
     Mod,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
     Eq,
     Ne,
     Lt,
     Le,
     Gt,
     Ge,
+    /// Short-circuiting `&&`.
     And,
+    /// Short-circuiting `||`.
     Or,
 }
 
 impl BinOp {
+    /// How tightly the operator binds, lower binds tighter.
     pub const fn precedence(&self) -> u8 {
         match self {
             Self::Mul | Self::Div | Self::Mod => 2,
             Self::Add | Self::Sub => 3,
-            Self::Lt | Self::Le | Self::Gt | Self::Ge => 4,
-            Self::Eq | Self::Ne => 5,
-            Self::And => 6,
-            Self::Or => 7,
+            Self::Shl | Self::Shr => 4,
+            Self::BitAnd => 5,
+            Self::BitXor => 6,
+            Self::BitOr => 7,
+            Self::Lt | Self::Le | Self::Gt | Self::Ge => 8,
+            Self::Eq | Self::Ne => 9,
+            Self::And => 10,
+            Self::Or => 11,
         }
     }
 }
@@ -87,29 +128,127 @@ pub struct AssignExpr {
     pub span: Span,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct IfExpr {
+    pub condition: Box<Expr>,
+    pub then_block: Block,
+    /// Either a block or another `if` expression.
+    pub else_branch: Option<Box<Expr>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoopExpr {
+    pub label: Option<Ident>,
+    pub body: Block,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WhileExpr {
+    pub label: Option<Ident>,
+    pub condition: Box<Expr>,
+    pub body: Block,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForExpr {
+    pub label: Option<Ident>,
+    pub binding: Ident,
+    pub iterator: Box<Expr>,
+    pub body: Block,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreakExpr {
+    pub label: Option<Ident>,
+    pub value: Option<Box<Expr>>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinueExpr {
+    pub label: Option<Ident>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub body: Expr,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchExpr {
+    pub expr: Box<Expr>,
+    pub arms: Vec<MatchArm>,
+    pub span: Span,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expr {
+    Literal(LiteralExpr),
     Paren(ParenExpr),
     Path(Path),
     Field(FieldExpr),
     Call(CallExpr),
+    MethodCall(MethodCallExpr),
     Index(IndexExpr),
     Unary(UnaryExpr),
     Binary(BinaryExpr),
     Assign(AssignExpr),
+    Block(Block),
+    If(IfExpr),
+    Loop(LoopExpr),
+    While(WhileExpr),
+    For(ForExpr),
+    Break(BreakExpr),
+    Continue(ContinueExpr),
+    Match(MatchExpr),
+    /// Placeholder for an expression that failed to parse, the error has already been
+    /// reported.
+    Error(Span),
 }
 
 impl Expr {
     pub fn span(&self) -> Span {
         match self {
+            Self::Literal(expr) => expr.span,
             Self::Paren(expr) => expr.span,
             Self::Path(expr) => expr.span,
             Self::Field(expr) => expr.span,
             Self::Call(expr) => expr.span,
+            Self::MethodCall(expr) => expr.span,
             Self::Index(expr) => expr.span,
             Self::Unary(expr) => expr.span,
             Self::Binary(expr) => expr.span,
             Self::Assign(expr) => expr.span,
+            Self::Block(block) => block.span,
+            Self::If(expr) => expr.span,
+            Self::Loop(expr) => expr.span,
+            Self::While(expr) => expr.span,
+            Self::For(expr) => expr.span,
+            Self::Break(expr) => expr.span,
+            Self::Continue(expr) => expr.span,
+            Self::Match(expr) => expr.span,
+            Self::Error(span) => *span,
         }
     }
+
+    /// Whether the expression ends with a block, these don't need a `;` when used as a
+    /// statement.
+    pub fn is_block_like(&self) -> bool {
+        matches!(
+            self,
+            Self::Block(_)
+                | Self::If(_)
+                | Self::Loop(_)
+                | Self::While(_)
+                | Self::For(_)
+                | Self::Match(_)
+        )
+    }
 }
@@ -1,8 +1,15 @@
-use crate::{expr::Expr, ident::Ident, ty::Type};
+use crate::{
+    attribute::Attribute, doc::Doc, expr::Expr, ident::Ident, span::Span, ty::Type,
+    visibility::Visibility,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Field {
+    pub visibility: Visibility,
     pub name: Ident,
     pub ty: Type,
     pub default: Option<Expr>,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
 }
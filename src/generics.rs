@@ -25,7 +25,7 @@ impl Generics {
 
     pub fn specialize(&self, specialized: &SpecGenerics) -> Result<SpecGenerics, Error> {
         if self.len() != specialized.len() {
-            let err = Error::new(format!("invalid number of generics")).with_note(format!(
+            let err = Error::new("invalid number of generics").with_note(format!(
                 "expected {} generics, found {}",
                 self,
                 specialized.spec()
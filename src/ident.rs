@@ -22,6 +22,14 @@ impl Ident {
     pub fn span(&self) -> Span {
         self.span
     }
+
+    pub fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
 }
 
 impl std::fmt::Display for Ident {
@@ -1,7 +1,11 @@
+pub mod attribute;
 pub mod block;
+pub mod build;
 pub mod class;
 pub mod data;
 pub mod decl;
+pub mod doc;
+pub mod enumeration;
 pub mod error;
 pub mod expr;
 pub mod field;
@@ -13,9 +17,11 @@ pub mod int;
 pub mod method;
 pub mod module;
 pub mod path;
+pub mod pattern;
 pub mod program;
 pub mod sources;
 pub mod span;
 pub mod specialization;
 pub mod stmt;
 pub mod ty;
+pub mod visibility;
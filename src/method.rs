@@ -1,4 +1,7 @@
-use crate::{function::Argument, generics::Generics, ident::Ident, span::Span, ty::Type};
+use crate::{
+    attribute::Attribute, block::Block, doc::Doc, function::Argument, generics::Generics,
+    ident::Ident, span::Span, ty::Type, visibility::Visibility,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct SelfArgument {
@@ -8,10 +11,21 @@ pub struct SelfArgument {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Method {
+    pub visibility: Visibility,
     pub name: Ident,
     pub generics: Generics,
     pub self_argument: Option<SelfArgument>,
     pub arguments: Vec<Argument>,
     pub return_ty: Type,
+    pub body: Block,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
     pub span: Span,
 }
+
+impl Method {
+    /// Whether the method is called on the class rather than on an instance.
+    pub fn is_static(&self) -> bool {
+        self.self_argument.is_none()
+    }
+}
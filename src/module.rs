@@ -1,16 +1,81 @@
 use std::collections::HashMap;
 
 use crate::{
-    data::{ClassId, FunctionId, ModuleId},
+    attribute::Attribute,
+    data::{ClassId, ConstId, EnumId, FunctionId, ModuleId, StaticId},
+    doc::Doc,
     ident::Ident,
     span::Span,
+    visibility::Visibility,
 };
 
+/// A name in a [`Module`], with the visibility it was declared or imported with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry<T> {
+    pub id: T,
+    pub visibility: Visibility,
+    /// Whether the name was imported by a glob, any other name shadows it.
+    pub is_glob: bool,
+}
+
+impl<T> Entry<T> {
+    pub const fn new(id: T, visibility: Visibility) -> Self {
+        Self {
+            id,
+            visibility,
+            is_glob: false,
+        }
+    }
+
+    /// A name imported by a glob, which is private to the importing module.
+    pub const fn glob(id: T) -> Self {
+        Self {
+            id,
+            visibility: Visibility::Private,
+            is_glob: true,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Module {
+    pub visibility: Visibility,
     pub name: Ident,
-    pub modules: HashMap<Ident, ModuleId>,
-    pub classes: HashMap<Ident, ClassId>,
-    pub functions: HashMap<Ident, FunctionId>,
+    /// The module containing this one, `None` for the root module.
+    pub parent: Option<ModuleId>,
+    pub modules: HashMap<Ident, Entry<ModuleId>>,
+    pub classes: HashMap<Ident, Entry<ClassId>>,
+    pub enums: HashMap<Ident, Entry<EnumId>>,
+    pub functions: HashMap<Ident, Entry<FunctionId>>,
+    pub consts: HashMap<Ident, Entry<ConstId>>,
+    pub statics: HashMap<Ident, Entry<StaticId>>,
+    pub docs: Vec<Doc>,
+    pub attributes: Vec<Attribute>,
     pub span: Span,
 }
+
+impl Module {
+    pub fn new(
+        visibility: Visibility,
+        name: Ident,
+        parent: Option<ModuleId>,
+        docs: Vec<Doc>,
+        attributes: Vec<Attribute>,
+        span: Span,
+    ) -> Self {
+        Self {
+            visibility,
+            name,
+            parent,
+            modules: HashMap::new(),
+            classes: HashMap::new(),
+            enums: HashMap::new(),
+            functions: HashMap::new(),
+            consts: HashMap::new(),
+            statics: HashMap::new(),
+            docs,
+            attributes,
+            span,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{ident::Ident, span::Span, specialization::Spec};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IdentSegment {
     pub name: Ident,
     pub spec: Spec,
@@ -19,7 +19,7 @@ impl std::fmt::Display for IdentSegment {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SelfSegment {
     pub span: Span,
 }
@@ -30,10 +30,35 @@ impl std::fmt::Display for SelfSegment {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SuperSegment {
+    pub span: Span,
+}
+
+impl std::fmt::Display for SuperSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "super")
+    }
+}
+
+/// `crate`, the root module of the program.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CrateSegment {
+    pub span: Span,
+}
+
+impl std::fmt::Display for CrateSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "crate")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PathSegment {
     IdentSegment(IdentSegment),
     SelfSegment(SelfSegment),
+    SuperSegment(SuperSegment),
+    CrateSegment(CrateSegment),
 }
 
 impl std::fmt::Display for PathSegment {
@@ -41,11 +66,13 @@ impl std::fmt::Display for PathSegment {
         match self {
             Self::IdentSegment(segment) => write!(f, "{}", segment),
             Self::SelfSegment(segment) => write!(f, "{}", segment),
+            Self::SuperSegment(segment) => write!(f, "{}", segment),
+            Self::CrateSegment(segment) => write!(f, "{}", segment),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Path {
     pub is_absolute: bool,
     pub segments: Vec<PathSegment>,
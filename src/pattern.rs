@@ -0,0 +1,39 @@
+use crate::{expr::LiteralKind, ident::Ident, path::Path, span::Span};
+
+/// A field in a struct variant pattern, `name: pattern` or just `name`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldPattern {
+    pub name: Ident,
+    pub pattern: Pattern,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternKind {
+    /// `_`.
+    Wildcard,
+    /// A name bound to the matched value.
+    Binding(Ident),
+    Literal(LiteralKind),
+    /// A unit variant or another item, e.g. `Shape::Empty`.
+    Path(Path),
+    /// `Shape::Circle(r)`.
+    Tuple {
+        path: Path,
+        fields: Vec<Pattern>,
+    },
+    /// `Shape::Rect { w, h: 0.0, .. }`.
+    Struct {
+        path: Path,
+        fields: Vec<FieldPattern>,
+        has_rest: bool,
+    },
+    /// `a | b`.
+    Or(Vec<Pattern>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+    pub kind: PatternKind,
+    pub span: Span,
+}
@@ -1,6 +1,9 @@
-use crate::decl::Decl;
+use crate::{attribute::Attribute, decl::Decl, doc::Doc};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Program {
     pub decls: Vec<Decl>,
+    pub docs: Vec<Doc>,
+    /// The inner `#![...]` attributes of the file.
+    pub attributes: Vec<Attribute>,
 }
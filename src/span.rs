@@ -1,6 +1,6 @@
 use crate::sources::SourceId;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Span {
     pub index: usize,
     pub length: usize,
@@ -39,6 +39,14 @@ impl Span {
         }
     }
 
+    /// Moves the span by `delta` bytes.
+    pub fn shifted(self, delta: isize) -> Span {
+        Span {
+            index: (self.index as isize + delta) as usize,
+            ..self
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         self.index == 0 && self.length == 0 && self.source == SourceId::null()
     }
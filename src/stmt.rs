@@ -1,4 +1,4 @@
-use crate::{expr::Expr, ident::Ident, span::Span, ty::Type};
+use crate::{attribute::Attribute, expr::Expr, ident::Ident, span::Span, ty::Type};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct LetStmt {
@@ -24,5 +24,6 @@ pub enum StmtKind {
 #[derive(Clone, Debug, PartialEq)]
 pub struct Stmt {
     pub kind: StmtKind,
+    pub attributes: Vec<Attribute>,
     pub span: Span,
 }
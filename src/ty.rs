@@ -36,6 +36,8 @@ pub enum Type {
     Function(Box<Signature>),
     Array(Box<Type>, usize),
     Class(ClassType),
+    /// A named type that is not resolved to a class or a generic yet.
+    Unresolved(Path),
 }
 
 impl Type {
@@ -63,6 +65,7 @@ impl std::fmt::Display for Type {
             Self::Function(signature) => write!(f, "{}", signature),
             Self::Array(ty, size) => write!(f, "[{}; {}]", ty, size),
             Self::Class(class) => write!(f, "{}", class.path),
+            Self::Unresolved(path) => write!(f, "{}", path),
         }
     }
 }
@@ -0,0 +1,16 @@
+use crate::span::Span;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Visibility {
+    /// Visible in the declaring module and its children.
+    #[default]
+    Private,
+    /// `pub`, visible everywhere the declaring module is.
+    Public(Span),
+}
+
+impl Visibility {
+    pub const fn is_public(&self) -> bool {
+        matches!(self, Self::Public(_))
+    }
+}